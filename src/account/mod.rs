@@ -0,0 +1,106 @@
+pub mod models;
+
+use self::models::{
+    Birthdate, Description, EmailStatus, Gender, GenderResponse, PhoneStatus, PromotionChannels,
+};
+use crate::ApiResult;
+
+use reqwest::Method;
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::Serialize;
+use serde_json::json;
+
+impl crate::Client {
+    async fn account_get<T: DeserializeOwned>(&self, path: &str) -> ApiResult<T> {
+        self.session
+            .request_json::<T, ()>(Method::GET, &format!("{}{}", crate::ACCOUNT, path), None)
+            .await
+    }
+
+    async fn account_update<B: Serialize + ?Sized>(&self, path: &str, body: &B) -> ApiResult<()> {
+        self.session
+            .request_json::<IgnoredAny, B>(
+                Method::POST,
+                &format!("{}{}", crate::ACCOUNT, path),
+                Some(body),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Get the profile description of the authenticated user, requires cookie
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     client.set_cookie("").await;
+    ///
+    ///     let description = client.account_description().await.unwrap();
+    ///     client
+    ///         .set_account_description(&format!("{}\nNow with more bots", description))
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn account_description(&self) -> ApiResult<String> {
+        Ok(self
+            .account_get::<Description>("/description")
+            .await?
+            .description)
+    }
+
+    /// Set the profile description of the authenticated user, requires cookie
+    pub async fn set_account_description(&self, description: &str) -> ApiResult<()> {
+        self.account_update("/description", &json!({ "description": description }))
+            .await
+    }
+
+    /// Get the birthdate of the authenticated user, requires cookie
+    pub async fn account_birthdate(&self) -> ApiResult<Birthdate> {
+        self.account_get("/birthdate").await
+    }
+
+    /// Set the birthdate of the authenticated user, requires cookie
+    pub async fn set_account_birthdate(&self, birthdate: &Birthdate) -> ApiResult<()> {
+        self.account_update("/birthdate", birthdate).await
+    }
+
+    /// Get the gender of the authenticated user, requires cookie
+    pub async fn account_gender(&self) -> ApiResult<Gender> {
+        Ok(self.account_get::<GenderResponse>("/gender").await?.gender)
+    }
+
+    /// Set the gender of the authenticated user, requires cookie
+    pub async fn set_account_gender(&self, gender: Gender) -> ApiResult<()> {
+        self.account_update("/gender", &json!({ "gender": gender.as_str() }))
+            .await
+    }
+
+    /// Get the social links of the authenticated user, requires cookie
+    pub async fn promotion_channels(&self) -> ApiResult<PromotionChannels> {
+        self.account_get("/promotion-channels").await
+    }
+
+    /// Set the social links of the authenticated user, requires cookie
+    pub async fn set_promotion_channels(&self, channels: &PromotionChannels) -> ApiResult<()> {
+        self.account_update("/promotion-channels", channels).await
+    }
+
+    /// Get the email address of the authenticated user and whether it is verified, requires cookie
+    pub async fn email_status(&self) -> ApiResult<EmailStatus> {
+        self.session
+            .request_json::<EmailStatus, ()>(
+                Method::GET,
+                &format!("{}/email", crate::ACCOUNT_SETTINGS),
+                None,
+            )
+            .await
+    }
+
+    /// Get the phone number of the authenticated user and whether it is verified, requires cookie
+    pub async fn phone_status(&self) -> ApiResult<PhoneStatus> {
+        self.account_get("/phone").await
+    }
+}
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Birthdate {
+    #[serde(rename = "birthMonth")]
+    pub birth_month: u8,
+    #[serde(rename = "birthDay")]
+    pub birth_day: u8,
+    #[serde(rename = "birthYear")]
+    pub birth_year: u16,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum Gender {
+    Unknown,
+    Male,
+    Female,
+}
+
+impl Gender {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Gender::Unknown => "Unknown",
+            Gender::Male => "Male",
+            Gender::Female => "Female",
+        }
+    }
+}
+
+impl From<u8> for Gender {
+    fn from(value: u8) -> Self {
+        match value {
+            2 => Gender::Male,
+            3 => Gender::Female,
+            _ => Gender::Unknown,
+        }
+    }
+}
+
+impl From<Gender> for u8 {
+    fn from(value: Gender) -> Self {
+        match value {
+            Gender::Unknown => 1,
+            Gender::Male => 2,
+            Gender::Female => 3,
+        }
+    }
+}
+
+/// Social links shown on the profile of the authenticated user
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PromotionChannels {
+    /// Who can see the links, e.g. `NoOne`, `Friends` or `AllUsers`
+    #[serde(rename = "promotionChannelsVisibilityPrivacy")]
+    pub visibility: String,
+    pub facebook: Option<String>,
+    pub twitter: Option<String>,
+    pub youtube: Option<String>,
+    pub twitch: Option<String>,
+    pub guilded: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EmailStatus {
+    /// Masked email address
+    #[serde(rename = "emailAddress")]
+    pub email_address: Option<String>,
+    pub verified: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PhoneStatus {
+    #[serde(rename = "countryCode")]
+    pub country_code: Option<String>,
+    pub prefix: Option<String>,
+    /// Masked phone number
+    pub phone: Option<String>,
+    #[serde(rename = "isVerified")]
+    pub is_verified: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub(crate) struct Description {
+    pub description: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct GenderResponse {
+    pub gender: Gender,
+}
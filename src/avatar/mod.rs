@@ -0,0 +1,206 @@
+pub mod models;
+
+use self::models::{
+    Avatar, AvatarScales, AvatarType, BodyColors, Outfit, OutfitDetails, OutfitPage, OutfitUpdate,
+    WearingResponse,
+};
+use crate::pagination::{self, PageStream};
+use crate::{ApiError, ApiResult, Https, User};
+
+use reqwest::Method;
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::Serialize;
+use serde_json::json;
+
+// Number of outfits requested per page
+const OUTFITS_PAGE_SIZE: u64 = 50;
+
+impl User {
+    /// Get the avatar currently worn by the user
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     let user = client.user(156).await;
+    ///     let avatar = user.avatar().await.unwrap();
+    ///
+    ///     for asset in avatar.assets {
+    ///         println!("{} ({})", asset.name, asset.asset_type.name);
+    ///     }
+    /// }
+    /// ```
+    pub async fn avatar(&self) -> ApiResult<Avatar> {
+        self.client
+            .as_ref()
+            .unwrap()
+            .request_json::<Avatar, ()>(
+                Method::GET,
+                &format!("{}/users/{}/avatar", crate::AVATAR, self.id.unwrap()),
+                None,
+            )
+            .await
+    }
+
+    /// Get the outfits of the user
+    pub fn outfits(&self) -> PageStream<Outfit> {
+        let client = self.client.clone().unwrap();
+        let user_id = self.id.unwrap();
+
+        // The outfits endpoint is page based, starting from 1
+        pagination::paginate_numbered(1, move |page| {
+            let client = client.clone();
+
+            async move {
+                let response = client
+                    .request_json::<OutfitPage, ()>(
+                        Method::GET,
+                        &format!(
+                            "{}/users/{}/outfits?page={}&itemsPerPage={}",
+                            crate::AVATAR,
+                            user_id,
+                            page,
+                            OUTFITS_PAGE_SIZE
+                        ),
+                        None,
+                    )
+                    .await?;
+
+                let more = response
+                    .total
+                    .is_none_or(|total| page * OUTFITS_PAGE_SIZE < total);
+                Ok((response.data, more))
+            }
+        })
+    }
+}
+
+impl crate::Client {
+    /// Get the assets, colors and scales saved in an outfit
+    pub async fn outfit_details(&self, outfit_id: u64) -> ApiResult<OutfitDetails> {
+        self.session
+            .request_json::<OutfitDetails, ()>(
+                Method::GET,
+                &format!("{}/outfits/{}/details", crate::AVATAR, outfit_id),
+                None,
+            )
+            .await
+    }
+}
+
+impl crate::Client {
+    // Send an avatar change of the authenticated user, mapping rejected assets to `ApiError::InvalidAssets`
+    async fn avatar_request<T, B>(&self, path: &str, body: Option<&B>) -> ApiResult<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize + ?Sized,
+    {
+        let mut builder = self
+            .session
+            .client
+            .post(format!("{}{}", crate::AVATAR, path));
+        if let Some(body) = body {
+            builder = builder.json(body);
+        }
+
+        let response = self.session.send(builder).await?;
+        let status_code = response.status();
+        let data = response.bytes().await?;
+
+        // Rejected assets are reported next to `"success": false`, which would otherwise decode to a generic error
+        if let Ok(wearing) = serde_json::from_slice::<WearingResponse>(&data) {
+            if !wearing.invalid_asset_ids.is_empty() {
+                return Err(ApiError::InvalidAssets {
+                    asset_ids: wearing.invalid_asset_ids,
+                });
+            }
+        }
+
+        Https::decode(status_code, &data)
+    }
+
+    // Same as `avatar_request`, discarding the response
+    async fn avatar_action<B>(&self, path: &str, body: Option<&B>) -> ApiResult<()>
+    where
+        B: Serialize + ?Sized,
+    {
+        self.avatar_request::<IgnoredAny, B>(path, body).await?;
+        Ok(())
+    }
+
+    /// Replace the assets worn by the authenticated user, requires cookie
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     client.set_cookie("").await;
+    ///
+    ///     match client.set_wearing_assets(&[607702162, 607700713]).await {
+    ///         Err(robloxapi::ApiError::InvalidAssets { asset_ids }) => {
+    ///             println!("Cannot wear {:?}", asset_ids)
+    ///         }
+    ///         result => result.unwrap(),
+    ///     }
+    /// }
+    /// ```
+    pub async fn set_wearing_assets(&self, asset_ids: &[u64]) -> ApiResult<()> {
+        self.avatar_action(
+            "/avatar/set-wearing-assets",
+            Some(&json!({ "assetIds": asset_ids })),
+        )
+        .await
+    }
+
+    /// Wear a single asset on the authenticated user, requires cookie
+    pub async fn wear_asset(&self, asset_id: u64) -> ApiResult<()> {
+        self.avatar_action::<()>(&format!("/avatar/assets/{}/wear", asset_id), None)
+            .await
+    }
+
+    /// Take off a single asset worn by the authenticated user, requires cookie
+    pub async fn remove_asset(&self, asset_id: u64) -> ApiResult<()> {
+        self.avatar_action::<()>(&format!("/avatar/assets/{}/remove", asset_id), None)
+            .await
+    }
+
+    /// Set the body colors of the authenticated user, requires cookie
+    pub async fn set_body_colors(&self, body_colors: &BodyColors) -> ApiResult<()> {
+        self.avatar_action("/avatar/set-body-colors", Some(body_colors))
+            .await
+    }
+
+    /// Set the body scales of the authenticated user, requires cookie.
+    /// Scales outside of the allowed ranges are rejected by Roblox.
+    pub async fn set_scales(&self, scales: &AvatarScales) -> ApiResult<()> {
+        self.avatar_action("/avatar/set-scales", Some(scales)).await
+    }
+
+    /// Set the avatar type of the authenticated user, requires cookie
+    pub async fn set_avatar_type(&self, avatar_type: AvatarType) -> ApiResult<()> {
+        self.avatar_action(
+            "/avatar/set-player-avatar-type",
+            Some(&json!({ "playerAvatarType": avatar_type })),
+        )
+        .await
+    }
+
+    /// Create an outfit for the authenticated user, requires cookie
+    pub async fn create_outfit(&self, outfit: &OutfitUpdate) -> ApiResult<Outfit> {
+        self.avatar_request("/outfits/create", Some(outfit)).await
+    }
+
+    /// Replace the contents of an outfit of the authenticated user, requires cookie
+    pub async fn update_outfit(&self, outfit_id: u64, outfit: &OutfitUpdate) -> ApiResult<()> {
+        self.avatar_action(&format!("/outfits/{}/update", outfit_id), Some(outfit))
+            .await
+    }
+
+    /// Delete an outfit of the authenticated user, requires cookie
+    pub async fn delete_outfit(&self, outfit_id: u64) -> ApiResult<()> {
+        self.avatar_action::<()>(&format!("/outfits/{}/delete", outfit_id), None)
+            .await
+    }
+}
@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvatarType {
+    R6,
+    R15,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct AvatarScales {
+    pub height: f64,
+    pub width: f64,
+    pub head: f64,
+    pub depth: f64,
+    pub proportion: f64,
+    #[serde(rename = "bodyType")]
+    pub body_type: f64,
+}
+
+/// Brick color ids of each body part
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BodyColors {
+    #[serde(rename = "headColorId")]
+    pub head_color_id: u32,
+    #[serde(rename = "torsoColorId")]
+    pub torso_color_id: u32,
+    #[serde(rename = "rightArmColorId")]
+    pub right_arm_color_id: u32,
+    #[serde(rename = "leftArmColorId")]
+    pub left_arm_color_id: u32,
+    #[serde(rename = "rightLegColorId")]
+    pub right_leg_color_id: u32,
+    #[serde(rename = "leftLegColorId")]
+    pub left_leg_color_id: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AssetType {
+    pub id: u32,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AvatarAsset {
+    pub id: u64,
+    pub name: String,
+    #[serde(rename = "assetType")]
+    pub asset_type: AssetType,
+    #[serde(rename = "currentVersionId")]
+    pub current_version_id: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Emote {
+    #[serde(rename = "assetId")]
+    pub asset_id: u64,
+    #[serde(rename = "assetName")]
+    pub asset_name: String,
+    pub position: u8,
+}
+
+/// Avatar currently worn by a user, see `User::avatar`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Avatar {
+    pub scales: AvatarScales,
+    #[serde(rename = "playerAvatarType")]
+    pub player_avatar_type: AvatarType,
+    #[serde(rename = "bodyColors")]
+    pub body_colors: BodyColors,
+    pub assets: Vec<AvatarAsset>,
+    #[serde(default)]
+    pub emotes: Vec<Emote>,
+    #[serde(rename = "defaultShirtApplied")]
+    pub default_shirt_applied: bool,
+    #[serde(rename = "defaultPantsApplied")]
+    pub default_pants_applied: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Outfit {
+    pub id: u64,
+    pub name: String,
+    #[serde(rename = "isEditable", default)]
+    pub is_editable: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OutfitDetails {
+    pub id: u64,
+    pub name: String,
+    pub assets: Vec<AvatarAsset>,
+    #[serde(rename = "bodyColors")]
+    pub body_colors: BodyColors,
+    pub scale: AvatarScales,
+    #[serde(rename = "playerAvatarType")]
+    pub player_avatar_type: AvatarType,
+    #[serde(rename = "outfitType")]
+    pub outfit_type: Option<String>,
+    #[serde(rename = "isEditable")]
+    pub is_editable: bool,
+}
+
+/// Contents of an outfit to create or update, see `Client::create_outfit`
+#[derive(Serialize, Debug, Clone)]
+pub struct OutfitUpdate {
+    pub name: String,
+    #[serde(rename = "assetIds")]
+    pub asset_ids: Vec<u64>,
+    #[serde(rename = "bodyColors")]
+    pub body_colors: BodyColors,
+    pub scale: AvatarScales,
+    #[serde(rename = "playerAvatarType")]
+    pub player_avatar_type: AvatarType,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct WearingResponse {
+    #[serde(rename = "invalidAssetIds", default)]
+    pub invalid_asset_ids: Vec<u64>,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct OutfitPage {
+    pub data: Vec<Outfit>,
+    pub total: Option<u64>,
+}
+
+impl std::fmt::Display for Outfit {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Outfit(id={}, name={})", self.id, self.name)
+    }
+}
@@ -0,0 +1,79 @@
+pub mod models;
+
+use self::models::{Badge, BadgeAward};
+use crate::pagination::{self, Page, PageStream};
+use crate::{ApiResult, BadgeId, User};
+
+use reqwest::{Method, Url};
+
+// Maximum number of badges accepted by the awarded dates endpoint per request
+const AWARDED_DATES_BATCH_SIZE: usize = 100;
+
+impl User {
+    /// Get the badges awarded to the user, most recent first
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     let user = client.user(156).await;
+    ///     let badges: Vec<_> = user.badges().try_collect().await.unwrap();
+    /// }
+    /// ```
+    pub fn badges(&self) -> PageStream<Badge> {
+        let url = Url::parse(&format!(
+            "{}/users/{}/badges?limit=100&sortOrder=Desc",
+            crate::BADGES,
+            self.id.unwrap()
+        ))
+        .expect("Failed to build badges url");
+
+        pagination::paginate(self.client.clone().unwrap(), url)
+    }
+
+    /// Get when the user was awarded the given badges, badges the user does not have are left out.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     let user = client.user(156).await;
+    ///
+    ///     for award in user.badge_awarded_dates(&[2124445684, 2124445685]).await.unwrap() {
+    ///         println!("{} awarded on {}", award.badge_id, award.awarded_date);
+    ///     }
+    /// }
+    /// ```
+    pub async fn badge_awarded_dates(&self, badge_ids: &[BadgeId]) -> ApiResult<Vec<BadgeAward>> {
+        let client = self.client.as_ref().unwrap();
+        let mut awards = Vec::new();
+
+        for batch in badge_ids.chunks(AWARDED_DATES_BATCH_SIZE) {
+            let ids = batch
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+
+            let page = client
+                .request_json::<Page<BadgeAward>, ()>(
+                    Method::GET,
+                    &format!(
+                        "{}/users/{}/badges/awarded-dates?badgeIds={}",
+                        crate::BADGES,
+                        self.id.unwrap(),
+                        ids
+                    ),
+                    None,
+                )
+                .await?;
+            awards.extend(page.data);
+        }
+
+        Ok(awards)
+    }
+}
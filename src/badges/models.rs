@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BadgeStatistics {
+    #[serde(rename = "pastDayAwardedCount")]
+    pub past_day_awarded_count: u64,
+    #[serde(rename = "awardedCount")]
+    pub awarded_count: u64,
+    #[serde(rename = "winRatePercentage")]
+    pub win_rate_percentage: f64,
+}
+
+/// The game or other entity which awards a badge
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BadgeAwarder {
+    pub id: u64,
+    #[serde(rename = "type")]
+    pub awarder_type: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Badge {
+    pub id: crate::BadgeId,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+    pub enabled: bool,
+    #[serde(rename = "iconImageId")]
+    pub icon_image_id: Option<u64>,
+    pub created: String,
+    pub updated: String,
+    pub statistics: Option<BadgeStatistics>,
+    pub awarder: Option<BadgeAwarder>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BadgeAward {
+    #[serde(rename = "badgeId")]
+    pub badge_id: crate::BadgeId,
+    #[serde(rename = "awardedDate")]
+    pub awarded_date: String,
+}
+
+impl std::fmt::Display for Badge {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Badge(id={}, name={})", self.id, self.name)
+    }
+}
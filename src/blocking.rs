@@ -0,0 +1,179 @@
+//! A synchronous wrapper around the async [`Client`](crate::Client), for use outside of an async runtime.
+//!
+//! Every call drives the async implementation to completion on a runtime owned by the client,
+//! so these types must not be used from within an async context.
+//!
+//! # Example
+//! ```no_run
+//! let mut client = robloxapi::blocking::Client::new();
+//! let mut game = client.game(7415484311).unwrap();
+//! let servers = game.servers().unwrap();
+//! ```
+use crate::{
+    ApiResult, CircuitBreakerConfig, DevProduct, Endpoint, GameBuilder, Method, MetricsRecorder,
+    Server, UserBuilder,
+};
+use futures::TryStreamExt;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Client {
+    /// Create a new blocking client instance
+    pub fn new() -> Self {
+        Self {
+            inner: crate::Client::new(),
+            runtime: Arc::new(
+                tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .expect("Failed to build blocking runtime"),
+            ),
+        }
+    }
+
+    /// See [`crate::Client::set_cookie`]
+    pub fn set_cookie(&mut self, cookie: &str) -> &mut Self {
+        self.runtime.block_on(self.inner.set_cookie(cookie));
+        self
+    }
+
+    /// See [`crate::Client::set_metrics_recorder`]
+    pub fn set_metrics_recorder(&mut self, recorder: impl MetricsRecorder + 'static) -> &mut Self {
+        self.inner.set_metrics_recorder(recorder);
+        self
+    }
+
+    /// See [`crate::Client::set_circuit_breaker`]
+    pub fn set_circuit_breaker(&mut self, config: CircuitBreakerConfig) -> &mut Self {
+        self.inner.set_circuit_breaker(config);
+        self
+    }
+
+    /// See [`crate::Client::user`]
+    pub fn user(&mut self, builder: impl UserBuilder) -> User {
+        User {
+            inner: self.runtime.block_on(self.inner.user(builder)),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// See [`crate::Client::current_user`]
+    pub fn current_user(&mut self) -> User {
+        User {
+            inner: self.runtime.block_on(self.inner.current_user()),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// See [`crate::Client::game`]
+    pub fn game(&self, builder: impl GameBuilder) -> ApiResult<Game> {
+        Ok(Game {
+            inner: self.runtime.block_on(self.inner.game(builder))?,
+            runtime: self.runtime.clone(),
+        })
+    }
+
+    /// See [`crate::Client::execute`]
+    pub fn execute<E: Endpoint + ?Sized>(&self, endpoint: &E) -> ApiResult<E::Response> {
+        self.runtime.block_on(self.inner.execute(endpoint))
+    }
+
+    /// See [`crate::Client::raw`]
+    pub fn raw(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<serde_json::Value>,
+    ) -> ApiResult<serde_json::Value> {
+        self.runtime.block_on(self.inner.raw(method, url, body))
+    }
+}
+
+/// Blocking counterpart of [`crate::User`], dereferences to it for field access
+#[derive(Debug, Clone)]
+pub struct User {
+    inner: crate::User,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl User {
+    /// See [`crate::User::friends`]
+    pub fn friends(&self) -> ApiResult<Vec<crate::User>> {
+        self.runtime.block_on(self.inner.friends().try_collect())
+    }
+
+    /// See [`crate::User::has_asset`]
+    pub fn has_asset(&self, asset_id: u64) -> ApiResult<bool> {
+        self.runtime.block_on(self.inner.has_asset(asset_id))
+    }
+
+    /// Get the underlying async user
+    pub fn into_inner(self) -> crate::User {
+        self.inner
+    }
+}
+
+impl Deref for User {
+    type Target = crate::User;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl DerefMut for User {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+/// Blocking counterpart of [`crate::Game`], dereferences to it for field access
+#[derive(Debug, Clone)]
+pub struct Game {
+    inner: crate::Game,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Game {
+    /// See [`crate::Game::servers`]
+    pub fn servers(&mut self) -> ApiResult<Vec<Server>> {
+        self.runtime.block_on(self.inner.servers())
+    }
+
+    /// See [`crate::Game::create_dev_product`]
+    pub fn create_dev_product(&mut self, name: &str, price: u32) -> ApiResult<DevProduct> {
+        self.runtime
+            .block_on(self.inner.create_dev_product(name, price))
+    }
+
+    /// Get the underlying async game
+    pub fn into_inner(self) -> crate::Game {
+        self.inner
+    }
+}
+
+impl Deref for Game {
+    type Target = crate::Game;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl DerefMut for Game {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
@@ -0,0 +1,261 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Configuration for the per host circuit breaker used by `Https`.
+///
+/// Once at least `minimum_requests` of the last `window` requests to a host have completed and the
+/// share of failures reaches `failure_ratio`, the circuit opens and requests to that host fail with
+/// `ApiError::CircuitOpen` until `cooldown` has elapsed. A single trial request is then let through
+/// (half-open); its outcome decides whether the circuit closes again or stays open for another cooldown.
+///
+/// Each attempt is cut off after `request_timeout` and counted as a failure, so a host which accepts
+/// connections but never answers also opens the circuit. `None` waits for as long as reqwest does.
+///
+/// `failure_ratio` must be in `(0.0, 1.0]` and `minimum_requests` must not exceed `window`,
+/// otherwise the circuit would open without failures or never open at all.
+#[derive(Debug, Clone, Copy)]
+pub struct CircuitBreakerConfig {
+    pub failure_ratio: f64,
+    pub minimum_requests: usize,
+    pub window: usize,
+    pub cooldown: Duration,
+    pub request_timeout: Option<Duration>,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_ratio: 0.5,
+            minimum_requests: 10,
+            window: 20,
+            cooldown: Duration::from_secs(30),
+            request_timeout: Some(Duration::from_secs(10)),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum State {
+    Closed,
+    Open(Instant),
+    // Holds when the trial request was let through
+    HalfOpen(Instant),
+}
+
+#[derive(Debug)]
+struct HostCircuit {
+    state: State,
+    // Outcomes of the most recent requests, true for a failure
+    outcomes: VecDeque<bool>,
+}
+
+#[derive(Debug)]
+pub(crate) struct CircuitBreakers {
+    config: CircuitBreakerConfig,
+    hosts: Mutex<HashMap<String, HostCircuit>>,
+}
+
+impl CircuitBreakers {
+    pub(crate) fn new(config: CircuitBreakerConfig) -> Self {
+        assert!(
+            config.failure_ratio > 0.0 && config.failure_ratio <= 1.0,
+            "failure_ratio must be in (0.0, 1.0], got {}",
+            config.failure_ratio
+        );
+        assert!(
+            config.minimum_requests <= config.window,
+            "minimum_requests ({}) must not exceed window ({})",
+            config.minimum_requests,
+            config.window
+        );
+
+        Self {
+            config,
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // Time after which an attempt is abandoned and recorded as a failure
+    pub(crate) fn request_timeout(&self) -> Option<Duration> {
+        self.config.request_timeout
+    }
+
+    // Check if a request to the host may be sent
+    pub(crate) fn allow(&self, host: &str) -> bool {
+        let mut hosts = self.hosts.lock().unwrap();
+        let Some(circuit) = hosts.get_mut(host) else {
+            return true;
+        };
+
+        match circuit.state {
+            State::Closed => true,
+            // Also let a new trial through if the previous one never reported back (e.g. its future was dropped)
+            State::Open(since) | State::HalfOpen(since)
+                if since.elapsed() >= self.config.cooldown =>
+            {
+                circuit.state = State::HalfOpen(Instant::now());
+                true
+            }
+            // Either still cooling down or a trial request is already in flight
+            State::Open(_) | State::HalfOpen(_) => false,
+        }
+    }
+
+    // Check if the circuit of the host has been opened, without starting a half-open trial
+    pub(crate) fn is_open(&self, host: &str) -> bool {
+        let hosts = self.hosts.lock().unwrap();
+        matches!(
+            hosts.get(host).map(|circuit| &circuit.state),
+            Some(State::Open(_))
+        )
+    }
+
+    // Record the outcome of a request to the host
+    pub(crate) fn record(&self, host: &str, failed: bool) {
+        let mut hosts = self.hosts.lock().unwrap();
+        let circuit = hosts.entry(host.to_owned()).or_insert_with(|| HostCircuit {
+            state: State::Closed,
+            outcomes: VecDeque::with_capacity(self.config.window),
+        });
+
+        match circuit.state {
+            State::HalfOpen(_) if failed => circuit.state = State::Open(Instant::now()),
+            State::HalfOpen(_) => {
+                circuit.state = State::Closed;
+                circuit.outcomes.clear();
+            }
+            State::Open(_) => {}
+            State::Closed => {
+                circuit.outcomes.push_back(failed);
+                while circuit.outcomes.len() > self.config.window {
+                    circuit.outcomes.pop_front();
+                }
+
+                let total = circuit.outcomes.len();
+                let failures = circuit.outcomes.iter().filter(|failed| **failed).count();
+                if total >= self.config.minimum_requests
+                    && failures as f64 / total as f64 >= self.config.failure_ratio
+                {
+                    circuit.state = State::Open(Instant::now());
+                    circuit.outcomes.clear();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    const HOST: &str = "users.roblox.com";
+
+    fn breakers(cooldown: Duration) -> CircuitBreakers {
+        CircuitBreakers::new(CircuitBreakerConfig {
+            failure_ratio: 0.5,
+            minimum_requests: 4,
+            window: 4,
+            cooldown,
+            request_timeout: None,
+        })
+    }
+
+    // Open the circuit of `HOST` by recording only failures
+    fn trip(breakers: &CircuitBreakers) {
+        for _ in 0..4 {
+            breakers.record(HOST, true);
+        }
+        assert!(breakers.is_open(HOST));
+    }
+
+    #[test]
+    fn opens_at_failure_ratio() {
+        let breakers = breakers(Duration::from_secs(60));
+        breakers.record(HOST, false);
+        breakers.record(HOST, false);
+        breakers.record(HOST, true);
+        assert!(breakers.allow(HOST));
+
+        breakers.record(HOST, true);
+        assert!(!breakers.allow(HOST));
+        // Other hosts are unaffected
+        assert!(breakers.allow("games.roblox.com"));
+    }
+
+    #[test]
+    fn respects_minimum_requests() {
+        let breakers = breakers(Duration::from_secs(60));
+        for _ in 0..3 {
+            breakers.record(HOST, true);
+        }
+        assert!(breakers.allow(HOST));
+    }
+
+    #[test]
+    fn fails_fast_during_cooldown() {
+        let breakers = breakers(Duration::from_secs(60));
+        trip(&breakers);
+        for _ in 0..3 {
+            assert!(!breakers.allow(HOST));
+        }
+    }
+
+    #[test]
+    fn allows_single_half_open_trial() {
+        let breakers = breakers(Duration::from_millis(50));
+        trip(&breakers);
+        thread::sleep(Duration::from_millis(60));
+
+        assert!(breakers.allow(HOST));
+        assert!(!breakers.allow(HOST));
+        assert!(!breakers.is_open(HOST));
+    }
+
+    #[test]
+    fn closes_after_successful_trial() {
+        let breakers = breakers(Duration::from_millis(50));
+        trip(&breakers);
+        thread::sleep(Duration::from_millis(60));
+
+        assert!(breakers.allow(HOST));
+        breakers.record(HOST, false);
+        assert!(breakers.allow(HOST));
+        assert!(breakers.allow(HOST));
+        // The failures from before the circuit opened are forgotten
+        breakers.record(HOST, true);
+        assert!(breakers.allow(HOST));
+    }
+
+    #[test]
+    fn reopens_after_failed_trial() {
+        let breakers = breakers(Duration::from_millis(50));
+        trip(&breakers);
+        thread::sleep(Duration::from_millis(60));
+
+        assert!(breakers.allow(HOST));
+        breakers.record(HOST, true);
+        assert!(breakers.is_open(HOST));
+        assert!(!breakers.allow(HOST));
+    }
+
+    #[test]
+    #[should_panic(expected = "failure_ratio")]
+    fn rejects_zero_failure_ratio() {
+        CircuitBreakers::new(CircuitBreakerConfig {
+            failure_ratio: 0.0,
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "minimum_requests")]
+    fn rejects_window_smaller_than_minimum_requests() {
+        CircuitBreakers::new(CircuitBreakerConfig {
+            minimum_requests: 10,
+            window: 5,
+            ..Default::default()
+        });
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{ApiResult, Client};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+
+/// A typed description of a Roblox API endpoint, for endpoints not covered by the crate.
+/// Executed with [`Client::execute`], which takes care of the cookie, x-csrf-token, retries and error decoding.
+/// The cookie and x-csrf-token are only sent over https to `roblox.com` or one of its subdomains.
+///
+/// # Example
+/// ```no_run
+/// use robloxapi::Endpoint;
+/// use serde::Deserialize;
+/// use std::borrow::Cow;
+///
+/// #[derive(Deserialize)]
+/// struct Count {
+///     count: u64,
+/// }
+///
+/// struct FriendCount(u64);
+///
+/// impl Endpoint for FriendCount {
+///     type Response = Count;
+///
+///     fn host(&self) -> &str {
+///         "friends.roblox.com"
+///     }
+///
+///     fn path(&self) -> Cow<'_, str> {
+///         format!("/v1/users/{}/friends/count", self.0).into()
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = robloxapi::Client::new();
+///     let count = client.execute(&FriendCount(242872495)).await.unwrap().count;
+/// }
+/// ```
+pub trait Endpoint {
+    /// Type the response body is decoded into
+    type Response: DeserializeOwned;
+
+    /// HTTP method, defaults to `GET`
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    /// Host of the endpoint, e.g. `users.roblox.com`.
+    /// Hosts outside of `roblox.com` are requested without the cookie and x-csrf-token.
+    fn host(&self) -> &str;
+
+    /// Path of the endpoint starting with `/`, e.g. `/v1/users/1`
+    fn path(&self) -> Cow<'_, str>;
+
+    /// Query parameters appended to the url
+    fn query(&self) -> Vec<(&str, String)> {
+        Vec::new()
+    }
+
+    /// JSON body sent with the request
+    fn body(&self) -> Option<serde_json::Value> {
+        None
+    }
+}
+
+impl Client {
+    /// Execute a custom [`Endpoint`] through the client session
+    pub async fn execute<E: Endpoint + ?Sized>(&self, endpoint: &E) -> ApiResult<E::Response> {
+        let url = format!("https://{}{}", endpoint.host(), endpoint.path());
+        let mut builder = self
+            .session
+            .client
+            .request(endpoint.method(), &url)
+            .query(&endpoint.query());
+        if let Some(body) = endpoint.body() {
+            builder = builder.json(&body);
+        }
+
+        let response = self.session.send(builder).await?;
+        crate::Https::de_to_result(response).await
+    }
+
+    /// Send a request to any url through the client session, returning the raw JSON response.
+    /// The cookie and x-csrf-token are only sent over https to `roblox.com` or one of its subdomains.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use robloxapi::Method;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = robloxapi::Client::new();
+    ///     let user = client
+    ///         .raw(Method::GET, "https://users.roblox.com/v1/users/242872495", None)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn raw(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<serde_json::Value>,
+    ) -> ApiResult<serde_json::Value> {
+        self.session.request_json(method, url, body.as_ref()).await
+    }
+}
@@ -22,6 +22,21 @@ pub enum ApiError {
 
     #[error("Request Error")]
     RequestError { reason: String },
+
+    #[error("Rate limited by Roblox, try again later")]
+    RateLimited,
+
+    #[error("The inventory of user {user_id} is private")]
+    PrivateInventory { user_id: u64 },
+
+    #[error("Roblox rejected assets {asset_ids:?}")]
+    InvalidAssets { asset_ids: Vec<u64> },
+
+    #[error("User {user_id} does not accept messages from the authenticated user")]
+    CannotMessage { user_id: u64 },
+
+    #[error("Circuit open for {host}, failing fast")]
+    CircuitOpen { host: String },
 }
 
 impl From<ApiError> for String {
@@ -7,6 +7,7 @@ use async_trait::async_trait;
 use reqwest::Method;
 
 #[async_trait]
+#[allow(clippy::new_ret_no_self, clippy::wrong_self_convention)]
 pub trait GameBuilder {
     async fn new(self, client: &mut crate::Https) -> ApiResult<Game>;
 }
@@ -14,7 +15,7 @@ pub trait GameBuilder {
 #[async_trait]
 impl GameBuilder for u64 {
     async fn new(self: u64, client: &mut crate::Https) -> ApiResult<Game> {
-        let udata: _ = client
+        let udata = client
             .request::<serde_json::Value>(
                 Method::GET,
                 &format!(
@@ -23,8 +24,7 @@ impl GameBuilder for u64 {
                     self
                 ),
             )
-            .await
-            .expect("Failed to get game universe info");
+            .await?;
 
         let fdata = client
             .request::<serde_json::Value>(
@@ -37,8 +37,7 @@ impl GameBuilder for u64 {
                         .expect("Failed to find game universe ID")
                 ),
             )
-            .await
-            .expect("Failed to get game root info");
+            .await?;
 
         Ok(Game {
             client: client.clone(),
@@ -59,7 +58,7 @@ impl GameBuilder for u64 {
 impl Game {
     /// Get a list of servers from the Game
     /// # Example
-    /// ```
+    /// ```no_run
     ///
     /// #[tokio::main]
     /// async fn main() {
@@ -67,17 +66,15 @@ impl Game {
     ///     // Place ID
     ///     let game_id = 7415484311;
     ///     let mut client = robloxapi::Client::new();
+    ///     let mut game = client.game(game_id).await.unwrap();
     ///     // List of servers
-    ///     let servers = client.game(game_id)
-    ///         .await
-    ///         .unwrap()
-    ///         .servers();
+    ///     let servers = game.servers().await;
     /// }
     ///
     /// ```
-    pub async fn servers(&mut self) -> Vec<Server> {
+    pub async fn servers(&mut self) -> ApiResult<Vec<Server>> {
         if let Some(servers) = self.servers.clone() {
-            servers
+            Ok(servers)
         } else {
             let mut servers: Vec<Server> = vec![];
             let mut data = self
@@ -90,8 +87,7 @@ impl Game {
                         self.place_id
                     ),
                 )
-                .await
-                .expect("Failed to get server list");
+                .await?;
 
             while let Some(cursor) = data.clone().get("nextPageCursor") {
                 if cursor.is_null() {
@@ -115,43 +111,19 @@ impl Game {
                             cursor.as_str().unwrap()
                         ),
                     )
-                    .await
-                    .expect("Failed to get server list");
-
-                if let Some(error) = data.get("errors") {
-                    if let Some(message) = error[0].get("message") {
-                        if message.as_str().unwrap() == "TooManyRequests" {
-                            println!("Rate limited, sleeping for 3 seconds");
-                            tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
-
-                            data = self
-                                .client
-                                .request(
-                                    Method::GET,
-                                    &format!(
-                                        "{}/games/{}/servers/Public?limit=100&cursor={}",
-                                        crate::GAMES,
-                                        self.place_id,
-                                        cursor.as_str().unwrap()
-                                    ),
-                                )
-                                .await
-                                .expect("Failed to get server list");
-                        }
-                    }
-
-                    continue;
-                }
+                    .await?;
             }
 
             self.servers = Some(servers.clone());
-            servers
+            Ok(servers)
         }
     }
 
     /// Create a developer product given name and price.
     /// # Example
-    /// ```
+    /// ```no_run
+    /// const COOKIE: &str = "";
+    ///
     /// #[tokio::main]
     /// async fn main() {
     ///     let mut client = robloxapi::Client::new();
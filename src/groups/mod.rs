@@ -0,0 +1,57 @@
+pub mod models;
+
+use self::models::GroupMembership;
+use crate::pagination::Page;
+use crate::{ApiResult, User};
+
+use reqwest::Method;
+
+impl User {
+    /// Get every group the user is in along with their role
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     let user = client.user(156).await;
+    ///
+    ///     let is_admin = user
+    ///         .groups()
+    ///         .await
+    ///         .unwrap()
+    ///         .iter()
+    ///         .any(|membership| membership.group.id == 7 && membership.role.rank >= 250);
+    /// }
+    /// ```
+    pub async fn groups(&self) -> ApiResult<Vec<GroupMembership>> {
+        Ok(self
+            .client
+            .as_ref()
+            .unwrap()
+            .request_json::<Page<GroupMembership>, ()>(
+                Method::GET,
+                &format!("{}/users/{}/groups/roles", crate::GROUPS, self.id.unwrap()),
+                None,
+            )
+            .await?
+            .data)
+    }
+
+    /// Get the primary group of the user, if they have set one
+    pub async fn primary_group(&self) -> ApiResult<Option<GroupMembership>> {
+        self.client
+            .as_ref()
+            .unwrap()
+            .request_json::<Option<GroupMembership>, ()>(
+                Method::GET,
+                &format!(
+                    "{}/users/{}/groups/primary/role",
+                    crate::GROUPS,
+                    self.id.unwrap()
+                ),
+                None,
+            )
+            .await
+    }
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Group {
+    pub id: u64,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(rename = "memberCount")]
+    pub member_count: Option<u64>,
+    #[serde(rename = "hasVerifiedBadge", default)]
+    pub has_verified_badge: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct GroupRole {
+    pub id: u64,
+    pub name: String,
+    /// Rank of the role within the group, from 0 (guest) to 255 (owner)
+    pub rank: u8,
+}
+
+/// A group a user is in along with their role, see `User::groups`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GroupMembership {
+    pub group: Group,
+    pub role: GroupRole,
+    #[serde(rename = "isPrimaryGroup", default)]
+    pub is_primary_group: bool,
+}
+
+impl std::fmt::Display for GroupMembership {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "GroupMembership(group={}, role={}, rank={})",
+            self.group.name, self.role.name, self.role.rank
+        )
+    }
+}
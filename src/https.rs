@@ -1,10 +1,39 @@
+use crate::circuit_breaker::{CircuitBreakerConfig, CircuitBreakers};
+use crate::metrics::{self, MetricsRecorder, NoopRecorder};
+use crate::runtime::{self, Timer};
 use crate::{errors::ApiError, errors::RobloxApiErrorResponse, ApiResult, Client};
-use reqwest::{header, Method, RequestBuilder, Response};
+use reqwest::{header, Method, RequestBuilder, Response, StatusCode};
 use serde::de::{self, DeserializeOwned};
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-#[derive(Debug, Clone)]
+const CSRF_HEADER: &str = "x-csrf-token";
+
+// Number of times a rate limited request is retried before the error is returned
+const MAX_RETRIES: u32 = 3;
+// Time to wait before retrying a rate limited request, multiplied by the attempt number
+const RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(3);
+
+#[derive(Clone)]
 pub struct Https {
     pub client: reqwest::Client,
+    // Client without the account cookie, used for hosts outside of roblox.com
+    pub(crate) anonymous: reqwest::Client,
+    pub(crate) recorder: Arc<dyn MetricsRecorder>,
+    pub(crate) circuit_breakers: Option<Arc<CircuitBreakers>>,
+    pub(crate) timer: Arc<dyn Timer>,
+    // Shared between clones so a regenerated token is picked up by every handle of the session
+    pub(crate) csrf_token: Arc<Mutex<Option<header::HeaderValue>>>,
+}
+
+impl std::fmt::Debug for Https {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Https")
+            .field("client", &self.client)
+            .field("circuit_breakers", &self.circuit_breakers)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for Https {
@@ -18,13 +47,13 @@ impl Client {
     /// Set the cookie for the client; This function is needed to execute specific API requests such as `.create_developer_product()`
     ///
     /// # Example
-    /// ```
+    /// ```no_run
     ///
-    /// let COOKIE: &str = "_|WARNING:-DO-NOT-SHARE-THIS.--Sharing-this-will-allow-someone-to-log-in-as-you-and-to-steal-your-ROBUX-and-items.|_8B1028";
+    /// const COOKIE: &str = "_|WARNING:-DO-NOT-SHARE-THIS.--Sharing-this-will-allow-someone-to-log-in-as-you-and-to-steal-your-ROBUX-and-items.|_8B1028";
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut client = robloxapi::Client();
+    ///     let mut client = robloxapi::Client::new();
     ///     client.set_cookie(COOKIE).await;
     /// }
     ///
@@ -36,26 +65,17 @@ impl Client {
             header::COOKIE,
             header::HeaderValue::from_str(&(".ROBLOSECURITY=".to_owned() + cookie)).unwrap(),
         );
-        headers.insert(
-            header::CONTENT_LENGTH,
-            header::HeaderValue::from_static("0"),
-        );
 
-        // Add the x-csrf-token to the headers
-        headers.insert(
-            header::HeaderName::from_static("x-csrf-token"),
-            header::HeaderValue::from(
-                reqwest::Client::new()
-                    .post("https://auth.roblox.com/v2/logout")
-                    .header("content-length", "0")
-                    .send()
-                    .await
-                    .expect("Failed to get X-CSRF-TOKEN")
-                    .headers()
-                    .get("x-csrf-token")
-                    .unwrap_or(&header::HeaderValue::from_static("")),
-            ),
-        );
+        // Get an initial x-csrf-token, it is regenerated by `Https::send` whenever Roblox rejects it
+        *self.session.csrf_token.lock().unwrap() = reqwest::Client::new()
+            .post("https://auth.roblox.com/v2/logout")
+            .header("content-length", "0")
+            .send()
+            .await
+            .expect("Failed to get X-CSRF-TOKEN")
+            .headers()
+            .get(CSRF_HEADER)
+            .cloned();
 
         // Create a new session with the cookie and token
         self.session.client = reqwest::Client::builder()
@@ -70,6 +90,39 @@ impl Client {
 
         self
     }
+
+    /// Set the recorder notified of every request made by this client, see [`MetricsRecorder`].
+    pub fn set_metrics_recorder(&mut self, recorder: impl MetricsRecorder + 'static) -> &mut Self {
+        self.session.recorder = Arc::new(recorder);
+        self
+    }
+
+    /// Enable a circuit breaker per Roblox host, failing fast with `ApiError::CircuitOpen` while a host is unhealthy.
+    ///
+    /// # Panics
+    /// Panics if `failure_ratio` is not in `(0.0, 1.0]` or `minimum_requests` exceeds `window`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let mut client = robloxapi::Client::new();
+    /// client.set_circuit_breaker(robloxapi::CircuitBreakerConfig {
+    ///     failure_ratio: 0.25,
+    ///     cooldown: Duration::from_secs(60),
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn set_circuit_breaker(&mut self, config: CircuitBreakerConfig) -> &mut Self {
+        self.session.circuit_breakers = Some(Arc::new(CircuitBreakers::new(config)));
+        self
+    }
+
+    /// Set the timer used to wait between retries, see [`Timer`].
+    pub fn set_timer(&mut self, timer: impl Timer + 'static) -> &mut Self {
+        self.session.timer = Arc::new(timer);
+        self
+    }
 }
 
 impl Https {
@@ -80,25 +133,58 @@ impl Https {
                 .cookie_store(true)
                 .build()
                 .unwrap(),
+            anonymous: reqwest::Client::new(),
+            recorder: Arc::new(NoopRecorder),
+            circuit_breakers: None,
+            timer: runtime::default_timer(),
+            csrf_token: Arc::new(Mutex::new(None)),
         }
     }
 
-    async fn de_to_result<T>(req: Response) -> ApiResult<T>
+    pub(crate) async fn de_to_result<T>(req: Response) -> ApiResult<T>
     where
         T: DeserializeOwned,
     {
         let status_code = req.status();
         let data = req.bytes().await?;
 
-        if let Ok(error) = serde_json::from_slice::<RobloxApiErrorResponse>(&data) {
-            if !error.is_empty() {
+        Https::decode(status_code, &data)
+    }
+
+    // Decode a response body, turning Roblox error models into `ApiError`
+    pub(crate) fn decode<T>(status_code: StatusCode, data: &[u8]) -> ApiResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        // Still rate limited after `send` ran out of retries
+        if status_code == StatusCode::TOO_MANY_REQUESTS {
+            return Err(ApiError::RateLimited);
+        }
+
+        if let Ok(error) = serde_json::from_slice::<RobloxApiErrorResponse>(data) {
+            if !error.is_empty() && error.success != Some(true) {
                 return Err(ApiError::Roblox {
                     status_code,
                     reason: error.reason().unwrap_or_else(|| "Unknown error".to_owned()),
                 });
             }
         }
-        Ok(serde_json::from_slice::<T>(&data)?)
+
+        if !status_code.is_success() {
+            return Err(ApiError::Roblox {
+                status_code,
+                reason: status_code
+                    .canonical_reason()
+                    .unwrap_or("Unknown error")
+                    .to_owned(),
+            });
+        }
+
+        // Some endpoints answer with an empty body on success
+        if data.is_empty() {
+            return Ok(serde_json::from_slice::<T>(b"null")?);
+        }
+        Ok(serde_json::from_slice::<T>(data)?)
     }
 
     // Send a get_request. Automatically handles the x-csrf token regeneration
@@ -106,15 +192,130 @@ impl Https {
     where
         T: de::DeserializeOwned,
     {
-        println!("{}", request_url);
-        let response = self
-            .client
-            .request(method.clone(), request_url)
-            .send()
-            .await
-            .expect("Request failed");
+        let response = self.send(self.client.request(method, request_url)).await?;
+
+        Https::de_to_result::<T>(response).await
+    }
+
+    /// Send a request with an optional JSON body and decode the response
+    pub async fn request_json<T, B>(
+        &self,
+        method: Method,
+        request_url: &str,
+        body: Option<&B>,
+    ) -> ApiResult<T>
+    where
+        T: de::DeserializeOwned,
+        B: Serialize + ?Sized,
+    {
+        let mut builder = self.client.request(method, request_url);
+        if let Some(body) = body {
+            builder = builder.json(body);
+        }
+
+        Https::de_to_result::<T>(self.send(builder).await?).await
+    }
+
+    // Send a request, retrying when rate limited or when the x-csrf-token has to be regenerated,
+    // and reporting every attempt to the metrics recorder.
+    // Requests to hosts outside of roblox.com, or over plain http, are sent without the cookie and x-csrf-token.
+    pub(crate) async fn send(&self, builder: RequestBuilder) -> ApiResult<Response> {
+        let mut request = builder.build()?;
+        // Roblox rejects body-less POST requests without a content length
+        if request.body().is_none() && request.method() != Method::GET {
+            request.headers_mut().insert(
+                header::CONTENT_LENGTH,
+                header::HeaderValue::from_static("0"),
+            );
+        }
+
+        let family = metrics::endpoint_family(request.url());
+        let host = request.url().host_str().unwrap_or_default().to_owned();
+        let trusted = request.url().scheme() == "https" && is_roblox_host(&host);
+        let client = if trusted {
+            &self.client
+        } else {
+            &self.anonymous
+        };
+        let mut retries = 0;
+        let mut csrf_refreshed = false;
+
+        if let Some(breakers) = &self.circuit_breakers {
+            if !breakers.allow(&host) {
+                return Err(ApiError::CircuitOpen { host });
+            }
+        }
+
+        loop {
+            // Stop retrying once other requests have opened the circuit in the meantime
+            if retries > 0 || csrf_refreshed {
+                if let Some(breakers) = &self.circuit_breakers {
+                    if breakers.is_open(&host) {
+                        return Err(ApiError::CircuitOpen { host });
+                    }
+                }
+            }
+
+            let mut attempt = request.try_clone().ok_or_else(|| ApiError::RequestError {
+                reason: "Request body cannot be cloned".to_owned(),
+            })?;
+            if let Some(breakers) = &self.circuit_breakers {
+                *attempt.timeout_mut() = breakers.request_timeout();
+            }
+            if trusted {
+                if let Some(token) = self.csrf_token.lock().unwrap().clone() {
+                    attempt.headers_mut().insert(CSRF_HEADER, token);
+                }
+            }
+
+            let started = Instant::now();
+            let result = client.execute(attempt).await;
+            let latency = started.elapsed();
+
+            let response = match result {
+                Ok(response) => response,
+                Err(error) => {
+                    self.recorder
+                        .record_request(&family, request.method(), None, latency);
+                    self.record_outcome(&host, true);
+                    return Err(error.into());
+                }
+            };
 
-        return Https::de_to_result::<T>(response).await;
+            let status = response.status();
+            self.recorder
+                .record_request(&family, request.method(), Some(status), latency);
+
+            // Roblox answers with a fresh token when the current one is missing or expired
+            if status == StatusCode::FORBIDDEN && trusted && !csrf_refreshed {
+                if let Some(token) = response.headers().get(CSRF_HEADER) {
+                    *self.csrf_token.lock().unwrap() = Some(token.clone());
+                    csrf_refreshed = true;
+                    self.recorder.record_retry(&family);
+                    continue;
+                }
+            }
+
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                self.recorder.record_rate_limited(&family);
+
+                if retries < MAX_RETRIES {
+                    retries += 1;
+                    self.recorder.record_retry(&family);
+                    self.timer.sleep(RATE_LIMIT_BACKOFF * retries).await;
+                    continue;
+                }
+            }
+
+            self.record_outcome(&host, status.is_server_error());
+            return Ok(response);
+        }
+    }
+
+    fn record_outcome(&self, host: &str, failed: bool) {
+        if let Some(breakers) = &self.circuit_breakers {
+            breakers.record(host, failed);
+        }
     }
 
     pub async fn post(&mut self, request_url: &str) -> RequestBuilder {
@@ -133,3 +334,55 @@ impl Https {
         let _: serde_json::Value = req.json().await.expect("Failed to validate cookie");
     }
 }
+
+// Check if the account cookie and x-csrf-token may be sent to the host
+pub(crate) fn is_roblox_host(host: &str) -> bool {
+    host == "roblox.com" || host.ends_with(".roblox.com")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_roblox_hosts_are_trusted() {
+        assert!(is_roblox_host("roblox.com"));
+        assert!(is_roblox_host("users.roblox.com"));
+        assert!(!is_roblox_host("notroblox.com"));
+        assert!(!is_roblox_host("roblox.com.example.org"));
+        assert!(!is_roblox_host("example.org"));
+        // Thumbnail images
+        assert!(!is_roblox_host("tr.rbxcdn.com"));
+        assert!(!is_roblox_host(""));
+    }
+
+    #[tokio::test]
+    async fn timed_out_attempts_open_the_circuit() {
+        // Accepts connections but never answers
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let connections: Vec<_> = listener.incoming().take(2).collect();
+            std::thread::sleep(Duration::from_secs(5));
+            drop(connections);
+        });
+
+        let mut https = Https::new();
+        https.circuit_breakers = Some(Arc::new(CircuitBreakers::new(CircuitBreakerConfig {
+            failure_ratio: 1.0,
+            minimum_requests: 2,
+            window: 2,
+            cooldown: Duration::from_secs(60),
+            request_timeout: Some(Duration::from_millis(100)),
+        })));
+
+        for _ in 0..2 {
+            let result = https.send(https.client.get(&url)).await;
+            assert!(matches!(result, Err(ApiError::RequestError { .. })));
+        }
+        assert!(matches!(
+            https.send(https.client.get(&url)).await,
+            Err(ApiError::CircuitOpen { .. })
+        ));
+    }
+}
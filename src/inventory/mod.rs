@@ -0,0 +1,241 @@
+pub mod models;
+
+use self::models::{
+    CanView, Collectible, CollectibleValue, CollectiblesValue, InventoryItem, ItemType, ResaleData,
+};
+use crate::pagination::{self, PageStream, SortOrder};
+use crate::{ApiError, ApiResult, User};
+
+use futures::stream::{self, StreamExt, TryStreamExt};
+use reqwest::{Method, StatusCode, Url};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+// Turn the forbidden response of a private inventory into `ApiError::PrivateInventory`
+pub(crate) fn map_private(user_id: u64, error: ApiError) -> ApiError {
+    match error {
+        ApiError::Roblox {
+            status_code: StatusCode::FORBIDDEN,
+            ..
+        } => ApiError::PrivateInventory { user_id },
+        error => error,
+    }
+}
+
+impl User {
+    /// Check if the user owns an item. Fails with `ApiError::PrivateInventory` if the
+    /// inventory of the user cannot be viewed by the client.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use robloxapi::ItemType;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     let user = client.user(156).await;
+    ///
+    ///     match user.owns(ItemType::GamePass, 1234).await {
+    ///         Ok(owned) => println!("Owns game pass: {}", owned),
+    ///         Err(robloxapi::ApiError::PrivateInventory { .. }) => println!("Inventory is private"),
+    ///         Err(error) => panic!("{}", error),
+    ///     }
+    /// }
+    /// ```
+    pub async fn owns(&self, item_type: ItemType, item_id: u64) -> ApiResult<bool> {
+        let user_id = self.id.unwrap();
+
+        self.client
+            .as_ref()
+            .unwrap()
+            .request_json::<bool, ()>(
+                Method::GET,
+                &format!(
+                    "{}/v1/users/{}/items/{}/{}/is-owned",
+                    crate::INVENTORY,
+                    user_id,
+                    item_type.as_str(),
+                    item_id
+                ),
+                None,
+            )
+            .await
+            .map_err(|error| map_private(user_id, error))
+    }
+
+    /// Check if user has asset, may require cookie
+    pub async fn has_asset(&self, asset_id: u64) -> ApiResult<bool> {
+        self.owns(ItemType::Asset, asset_id).await
+    }
+
+    /// Check if user has game pass, may require cookie
+    pub async fn has_game_pass(&self, game_pass_id: u64) -> ApiResult<bool> {
+        self.owns(ItemType::GamePass, game_pass_id).await
+    }
+
+    /// Check if user has badge, may require cookie
+    pub async fn has_badge(&self, badge_id: u64) -> ApiResult<bool> {
+        self.owns(ItemType::Badge, badge_id).await
+    }
+
+    /// Check if user has bundle, may require cookie
+    pub async fn has_bundle(&self, bundle_id: u64) -> ApiResult<bool> {
+        self.owns(ItemType::Bundle, bundle_id).await
+    }
+
+    /// Check if the inventory of the user can be viewed by the client
+    pub async fn can_view_inventory(&self) -> ApiResult<bool> {
+        Ok(self
+            .client
+            .as_ref()
+            .unwrap()
+            .request_json::<CanView, ()>(
+                Method::GET,
+                &format!(
+                    "{}/v1/users/{}/can-view-inventory",
+                    crate::INVENTORY,
+                    self.id.unwrap()
+                ),
+                None,
+            )
+            .await?
+            .can_view)
+    }
+
+    /// Get the items of the given asset types (e.g. `Hat`, `Shirt`) owned by the user.
+    /// The stream yields `ApiError::PrivateInventory` if the inventory cannot be viewed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use robloxapi::SortOrder;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     let user = client.user(156).await;
+    ///
+    ///     let mut items = user.inventory(&["Hat", "HairAccessory"], SortOrder::Desc);
+    ///     while let Some(item) = items.try_next().await.unwrap() {
+    ///         println!("{}", item);
+    ///     }
+    /// }
+    /// ```
+    pub fn inventory(
+        &self,
+        asset_types: &[&str],
+        sort_order: SortOrder,
+    ) -> PageStream<InventoryItem> {
+        let user = self.clone();
+        let user_id = self.id.unwrap();
+        let url = Url::parse_with_params(
+            &format!("{}/v2/users/{}/inventory", crate::INVENTORY, user_id),
+            &[
+                ("assetTypes", asset_types.join(",").as_str()),
+                ("limit", "100"),
+                ("sortOrder", sort_order.as_str()),
+            ],
+        )
+        .expect("Failed to build inventory url");
+
+        let items = async move {
+            if !user.can_view_inventory().await? {
+                return Err(ApiError::PrivateInventory { user_id });
+            }
+            Ok(pagination::paginate::<InventoryItem>(
+                user.client.unwrap(),
+                url,
+            ))
+        };
+
+        stream::once(items)
+            .try_flatten()
+            .map_err(move |error| map_private(user_id, error))
+            .boxed()
+    }
+
+    /// Get the limited items owned by the user
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     let user = client.user(156).await;
+    ///     let collectibles: Vec<_> = user.collectibles().try_collect().await.unwrap();
+    /// }
+    /// ```
+    pub fn collectibles(&self) -> PageStream<Collectible> {
+        let user_id = self.id.unwrap();
+        let url = Url::parse(&format!(
+            "{}/v1/users/{}/assets/collectibles?limit=100&sortOrder=Asc",
+            crate::INVENTORY,
+            user_id
+        ))
+        .expect("Failed to build collectibles url");
+
+        pagination::paginate::<Collectible>(self.client.clone().unwrap(), url)
+            .map_err(move |error| map_private(user_id, error))
+            .boxed()
+    }
+
+    /// Total the recent average price of the limited items owned by the user,
+    /// optionally fetching the current resale data of every item.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     let user = client.user(156).await;
+    ///     let value = user.collectibles_value(false).await.unwrap();
+    ///     println!("Total RAP: {}", value.total_rap);
+    /// }
+    /// ```
+    pub async fn collectibles_value(&self, with_resale: bool) -> ApiResult<CollectiblesValue> {
+        let client = crate::Client {
+            session: self.client.clone().unwrap(),
+        };
+        let items: Vec<Collectible> = self.collectibles().try_collect().await?;
+        let total_rap = items
+            .iter()
+            .filter_map(|item| item.recent_average_price)
+            .sum();
+
+        // Copies of the same asset share their resale data
+        let mut resale: HashMap<u64, ResaleData> = HashMap::new();
+        if with_resale {
+            for item in &items {
+                if let Entry::Vacant(entry) = resale.entry(item.asset_id) {
+                    entry.insert(client.resale_data(item.asset_id).await?);
+                }
+            }
+        }
+
+        Ok(CollectiblesValue {
+            total_rap,
+            items: items
+                .into_iter()
+                .map(|item| CollectibleValue {
+                    resale: resale.get(&item.asset_id).cloned(),
+                    item,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl crate::Client {
+    /// Get the current resale data of a limited asset
+    pub async fn resale_data(&self, asset_id: u64) -> ApiResult<ResaleData> {
+        self.session
+            .request_json::<ResaleData, ()>(
+                Method::GET,
+                &format!("{}/{}/resale-data", crate::ECONOMY, asset_id),
+                None,
+            )
+            .await
+    }
+}
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+
+/// Kind of item an ownership check is made for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemType {
+    Asset,
+    GamePass,
+    Badge,
+    Bundle,
+}
+
+impl ItemType {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            ItemType::Asset => "Asset",
+            ItemType::GamePass => "GamePass",
+            ItemType::Badge => "Badge",
+            ItemType::Bundle => "Bundle",
+        }
+    }
+}
+
+/// An item in the inventory of a user, see `User::inventory`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InventoryItem {
+    #[serde(rename = "assetId")]
+    pub asset_id: u64,
+    pub name: String,
+    /// Name of the asset type, e.g. `Hat`
+    #[serde(rename = "assetType")]
+    pub asset_type: String,
+    pub created: Option<String>,
+}
+
+/// A limited item owned by a user, see `User::collectibles`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Collectible {
+    #[serde(rename = "userAssetId")]
+    pub user_asset_id: u64,
+    #[serde(rename = "serialNumber")]
+    pub serial_number: Option<u64>,
+    #[serde(rename = "assetId")]
+    pub asset_id: u64,
+    pub name: String,
+    #[serde(rename = "recentAveragePrice")]
+    pub recent_average_price: Option<u64>,
+    #[serde(rename = "originalPrice")]
+    pub original_price: Option<u64>,
+    #[serde(rename = "assetStock")]
+    pub asset_stock: Option<u64>,
+    #[serde(rename = "isOnHold", default)]
+    pub is_on_hold: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DataPoint {
+    pub value: u64,
+    pub date: String,
+}
+
+/// Current resale data of a limited asset, see `Client::resale_data`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResaleData {
+    #[serde(rename = "assetStock")]
+    pub asset_stock: Option<u64>,
+    pub sales: u64,
+    #[serde(rename = "numberRemaining")]
+    pub number_remaining: Option<u64>,
+    #[serde(rename = "recentAveragePrice")]
+    pub recent_average_price: u64,
+    #[serde(rename = "originalPrice")]
+    pub original_price: Option<u64>,
+    #[serde(rename = "priceDataPoints", default)]
+    pub price_data_points: Vec<DataPoint>,
+    #[serde(rename = "volumeDataPoints", default)]
+    pub volume_data_points: Vec<DataPoint>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CollectibleValue {
+    pub item: Collectible,
+    /// Only fetched when requested, see `User::collectibles_value`
+    pub resale: Option<ResaleData>,
+}
+
+/// Value of the limited items of a user, see `User::collectibles_value`
+#[derive(Debug, Clone)]
+pub struct CollectiblesValue {
+    /// Sum of the recent average price of every item
+    pub total_rap: u64,
+    pub items: Vec<CollectibleValue>,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct CanView {
+    #[serde(rename = "canView")]
+    pub can_view: bool,
+}
+
+impl std::fmt::Display for Collectible {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Collectible(asset_id={}, name={}, serial_number={}, rap={})",
+            self.asset_id,
+            self.name,
+            self.serial_number
+                .map_or_else(|| "none".to_owned(), |serial| serial.to_string()),
+            self.recent_average_price.unwrap_or_default()
+        )
+    }
+}
+
+impl std::fmt::Display for InventoryItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "InventoryItem(asset_id={}, name={}, asset_type={})",
+            self.asset_id, self.name, self.asset_type
+        )
+    }
+}
@@ -1,38 +1,87 @@
+// Every Roblox API is served over https, so reqwest needs a TLS backend
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+compile_error!("robloxapi requires either the `native-tls` or the `rustls` feature to be enabled");
+
+mod account;
+mod avatar;
+mod badges;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod circuit_breaker;
+mod endpoint;
 mod errors;
 mod games;
+mod groups;
 mod https;
+mod inventory;
+mod messages;
+mod metrics;
+mod pagination;
+mod presence;
+mod runtime;
+mod thumbnails;
 mod users;
 
-pub use errors::ApiResult;
+pub use account::models::{Birthdate, EmailStatus, Gender, PhoneStatus, PromotionChannels};
+pub use avatar::models::{
+    AssetType, Avatar, AvatarAsset, AvatarScales, AvatarType, BodyColors, Emote, Outfit,
+    OutfitDetails, OutfitUpdate,
+};
+pub use badges::models::{Badge, BadgeAward, BadgeAwarder, BadgeStatistics};
+pub use circuit_breaker::CircuitBreakerConfig;
+pub use endpoint::Endpoint;
+pub use errors::{ApiError, ApiResult};
 pub use games::models::{DevProduct, Game, Server};
 pub use games::GameBuilder;
+pub use groups::models::{Group, GroupMembership, GroupRole};
 pub use https::Https;
-pub use users::models::User;
+pub use inventory::models::{
+    Collectible, CollectibleValue, CollectiblesValue, DataPoint, InventoryItem, ItemType,
+    ResaleData,
+};
+pub use messages::models::{FailedMessage, Message, MessageTab, MessageUser};
+#[cfg(feature = "metrics")]
+pub use metrics::MetricsCrateRecorder;
+pub use metrics::{MetricsRecorder, NoopRecorder};
+pub use pagination::{PageStream, SortOrder};
+pub use presence::models::{PresenceChange, PresenceType, UserPresence};
+#[cfg(feature = "tokio")]
+pub use runtime::TokioTimer;
+pub use runtime::{ThreadTimer, Timer};
+pub use thumbnails::models::{
+    AvatarThumbnailType, Thumbnail, ThumbnailFormat, ThumbnailImage, ThumbnailOptions,
+    ThumbnailState,
+};
+pub use users::models::{FriendRequest, FriendRequestDetails, SearchUser, User, UsernameLookup};
 pub use users::UserBuilder;
 
-use reqwest::Method;
+pub use reqwest::{Method, StatusCode};
 
 // STATIC URLS
-pub(crate) const BASE: &str = "https://api.roblox.com";
 #[allow(dead_code)]
 pub(crate) const AUTH: &str = "https://auth.roblox.com/v1/account/pin/unlock";
-#[allow(dead_code)]
 pub(crate) const ACCOUNT: &str = "https://accountinformation.roblox.com/v1";
-#[allow(dead_code)]
+pub(crate) const ACCOUNT_SETTINGS: &str = "https://accountsettings.roblox.com/v1";
 pub(crate) const MESSAGES: &str = "https://privatemessages.roblox.com/v1";
 pub(crate) const USER: &str = "https://users.roblox.com/v1";
+pub(crate) const FRIENDS: &str = "https://friends.roblox.com/v1";
+pub(crate) const PREMIUM: &str = "https://premiumfeatures.roblox.com/v1";
+pub(crate) const BADGES: &str = "https://badges.roblox.com/v1";
+pub(crate) const AVATAR: &str = "https://avatar.roblox.com/v1";
+pub(crate) const THUMBNAILS: &str = "https://thumbnails.roblox.com/v1";
 pub(crate) const GAMES: &str = "https://games.roblox.com/v1";
-#[allow(dead_code)]
 pub(crate) const GROUPS: &str = "https://groups.roblox.com/v1";
-#[allow(dead_code)]
 pub(crate) const PRESENCE: &str = "https://presence.roblox.com/v1/presence/users";
-#[allow(dead_code)]
 pub(crate) const ECONOMY: &str = "https://economy.roblox.com/v1/assets";
-#[allow(dead_code)]
 pub(crate) const INVENTORY: &str = "https://inventory.roblox.com";
 #[allow(dead_code)]
 pub(crate) const DEVPAGE: &str = "https://apis.roblox.com/developer-products/v1/universes";
 
+/// Id of a Roblox user
+pub type UserId = u64;
+/// Id of a Roblox badge
+pub type BadgeId = u64;
+
 #[derive(Debug, Clone)]
 pub struct Client {
     pub session: crate::Https,
@@ -53,8 +102,12 @@ impl Client {
     }
 
     /// Create a new user given user_id
+    ///
+    /// # Panics
+    /// Panics if the user cannot be fetched. Looking a user up by name also panics when the name
+    /// does not exist, [`Client::users_by_names`] reports missing names instead.
     /// ## Example
-    /// ```
+    /// ```no_run
     ///
     /// #[tokio::main]
     /// async fn main() {
@@ -68,7 +121,7 @@ impl Client {
 
     /// Get the current user. Must be logged in with a cookie to get current_user
     /// # Example
-    /// ```
+    /// ```no_run
     /// #[tokio::main]
     /// async fn main() {
     ///     let COOKIE: &str = "";
@@ -76,21 +129,21 @@ impl Client {
     ///     client.set_cookie(COOKIE).await;
     ///     let current_user = client.current_user().await;
     /// }
-    //
+    /// ```
     pub async fn current_user(self: &mut Client) -> User {
         let data = self
             .session
-            .request::<serde_json::Value>(Method::GET, "https://www.roblox.com/mobileapi/userinfo")
+            .request::<serde_json::Value>(Method::GET, &format!("{}/users/authenticated", USER))
             .await
             .expect("Failed to get user info");
 
-        let builder = data.get("UserID").unwrap().as_u64().unwrap();
+        let builder = data.get("id").unwrap().as_u64().unwrap();
         UserBuilder::new(builder, &mut self.session).await
     }
 
     /// Returns a Game struct given the place ID. Get information about a game.
     /// ## Example
-    /// ```
+    /// ```no_run
     ///
     /// #[tokio::main]
     /// async fn main() {
@@ -100,7 +153,7 @@ impl Client {
     ///     // Create a new game given place id
     ///     let game = client.game(place_id).await;
     /// }
-    /// ````
+    /// ```
     pub async fn game(&self, builder: impl GameBuilder) -> ApiResult<Game> {
         builder.new(&mut self.session.clone()).await
     }
@@ -0,0 +1,303 @@
+pub mod models;
+
+use self::models::{
+    Announcements, BulkResponse, FailedMessage, Message, MessagePage, MessageTab, SendMessage,
+};
+use crate::pagination::{self, Count, PageStream};
+use crate::{ApiError, ApiResult, UserId};
+
+use futures::stream::{self, StreamExt, TryStreamExt};
+use reqwest::Method;
+use serde::de::IgnoredAny;
+use serde::Deserialize;
+use serde_json::json;
+
+// Number of messages requested per page
+const MESSAGES_PAGE_SIZE: u64 = 20;
+
+// Maximum number of messages per bulk action request
+const MESSAGES_PER_REQUEST: usize = 20;
+
+#[derive(Deserialize)]
+struct CanMessage {
+    #[serde(rename = "canMessage")]
+    can_message: bool,
+}
+
+#[derive(Deserialize)]
+struct AuthenticatedUser {
+    id: UserId,
+}
+
+impl crate::Client {
+    /// Get the private messages of the authenticated user in the given tab, newest first, requires cookie
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use robloxapi::MessageTab;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     client.set_cookie("").await;
+    ///
+    ///     let mut messages = client.messages(MessageTab::Inbox);
+    ///     while let Some(message) = messages.try_next().await.unwrap() {
+    ///         println!("{}: {}", message.sender.name, message.subject);
+    ///     }
+    /// }
+    /// ```
+    pub fn messages(&self, tab: MessageTab) -> PageStream<Message> {
+        let client = self.session.clone();
+
+        // Announcements are not paginated, they are all returned at once
+        if tab == MessageTab::News {
+            return stream::once(async move {
+                client
+                    .request_json::<Announcements, ()>(
+                        Method::GET,
+                        &format!("{}/announcements", crate::MESSAGES),
+                        None,
+                    )
+                    .await
+                    .map(|announcements| stream::iter(announcements.data.into_iter().map(Ok)))
+            })
+            .try_flatten()
+            .boxed();
+        }
+
+        // The messages endpoint is page based, starting from 0
+        pagination::paginate_numbered(0, move |page| {
+            let client = client.clone();
+
+            async move {
+                let response = client
+                    .request_json::<MessagePage, ()>(
+                        Method::GET,
+                        &format!(
+                            "{}/messages?pageNumber={}&pageSize={}&messageTab={}",
+                            crate::MESSAGES,
+                            page,
+                            MESSAGES_PAGE_SIZE,
+                            tab.as_str()
+                        ),
+                        None,
+                    )
+                    .await?;
+
+                let more = page + 1 < response.total_pages;
+                Ok((response.data, more))
+            }
+        })
+    }
+
+    /// Get the number of unread private messages of the authenticated user, requires cookie
+    pub async fn unread_message_count(&self) -> ApiResult<u64> {
+        Ok(self
+            .session
+            .request_json::<Count, ()>(
+                Method::GET,
+                &format!("{}/messages/unread/count", crate::MESSAGES),
+                None,
+            )
+            .await?
+            .count)
+    }
+
+    /// Get a single private message of the authenticated user, requires cookie
+    pub async fn message(&self, message_id: u64) -> ApiResult<Message> {
+        self.session
+            .request_json::<Message, ()>(
+                Method::GET,
+                &format!("{}/messages/{}", crate::MESSAGES, message_id),
+                None,
+            )
+            .await
+    }
+
+    // Apply a bulk action to messages, collecting the ones Roblox failed to update
+    async fn messages_action(&self, action: &str, ids: &[u64]) -> ApiResult<Vec<FailedMessage>> {
+        let mut failed = Vec::new();
+        for chunk in ids.chunks(MESSAGES_PER_REQUEST) {
+            let response = self
+                .session
+                .request_json::<BulkResponse, _>(
+                    Method::POST,
+                    &format!("{}/messages/{}", crate::MESSAGES, action),
+                    Some(&json!({ "messageIds": chunk })),
+                )
+                .await?;
+            failed.extend(response.failed_messages);
+        }
+        Ok(failed)
+    }
+
+    /// Mark messages as read, returning the ones Roblox failed to update. Requires cookie
+    pub async fn mark_messages_read(&self, ids: &[u64]) -> ApiResult<Vec<FailedMessage>> {
+        self.messages_action("mark-read", ids).await
+    }
+
+    /// Mark messages as unread, returning the ones Roblox failed to update. Requires cookie
+    pub async fn mark_messages_unread(&self, ids: &[u64]) -> ApiResult<Vec<FailedMessage>> {
+        self.messages_action("mark-unread", ids).await
+    }
+
+    /// Move messages to the archive, returning the ones Roblox failed to update. Requires cookie
+    pub async fn archive_messages(&self, ids: &[u64]) -> ApiResult<Vec<FailedMessage>> {
+        self.messages_action("archive", ids).await
+    }
+
+    /// Move messages out of the archive, returning the ones Roblox failed to update. Requires cookie
+    pub async fn unarchive_messages(&self, ids: &[u64]) -> ApiResult<Vec<FailedMessage>> {
+        self.messages_action("unarchive", ids).await
+    }
+
+    /// Check if the authenticated user can send a private message to the given user, requires cookie
+    pub async fn can_message(&self, user_id: UserId) -> ApiResult<bool> {
+        Ok(self
+            .session
+            .request_json::<CanMessage, ()>(
+                Method::GET,
+                &format!("{}/messages/{}/can-message", crate::MESSAGES, user_id),
+                None,
+            )
+            .await?
+            .can_message)
+    }
+
+    // Send a message after checking the recipient accepts messages from the authenticated user
+    async fn send(
+        &self,
+        recipient_id: UserId,
+        subject: &str,
+        body: &str,
+        reply_message_id: Option<u64>,
+    ) -> ApiResult<()> {
+        if !self.can_message(recipient_id).await? {
+            return Err(ApiError::CannotMessage {
+                user_id: recipient_id,
+            });
+        }
+
+        let sender = self
+            .session
+            .request_json::<AuthenticatedUser, ()>(
+                Method::GET,
+                &format!("{}/users/authenticated", crate::USER),
+                None,
+            )
+            .await?;
+
+        let message = SendMessage {
+            user_id: sender.id,
+            recipient_id,
+            subject,
+            body,
+            reply_message_id,
+            include_previous_message: reply_message_id.is_some(),
+        };
+        self.session
+            .request_json::<IgnoredAny, _>(
+                Method::POST,
+                &format!("{}/messages/send", crate::MESSAGES),
+                Some(&message),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Send a private message to the given user, requires cookie.
+    /// Returns `ApiError::CannotMessage` when the user does not accept messages from the authenticated user.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     client.set_cookie("").await;
+    ///
+    ///     client
+    ///         .send_message(156, "Hello", "Sent with robloxapi")
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn send_message(
+        &self,
+        recipient_id: UserId,
+        subject: &str,
+        body: &str,
+    ) -> ApiResult<()> {
+        self.send(recipient_id, subject, body, None).await
+    }
+
+    /// Reply to a received message, quoting it in the reply. Requires cookie
+    pub async fn reply_to_message(&self, message: &Message, body: &str) -> ApiResult<()> {
+        self.send(
+            message.sender.id,
+            &format!("RE: {}", message.subject),
+            body,
+            Some(message.id),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_message_page() {
+        let body = br#"{
+            "collectionSize": 1,
+            "collection": [{
+                "id": 1234567890,
+                "sender": {"hasVerifiedBadge": true, "id": 1, "name": "Roblox", "displayName": "Roblox"},
+                "recipient": {"hasVerifiedBadge": false, "id": 156, "name": "builderman", "displayName": "builderman"},
+                "subject": "Welcome to Roblox!",
+                "body": "Hello builderman",
+                "created": "2024-01-01T00:00:00.000Z",
+                "updated": "2024-01-01T00:00:00.000Z",
+                "isRead": false,
+                "isSystemMessage": true,
+                "isReportAbuseDisplayed": false
+            }],
+            "totalCollectionSize": 41,
+            "totalPages": 3,
+            "pageNumber": 0
+        }"#;
+
+        let page: MessagePage = crate::Https::decode(reqwest::StatusCode::OK, body).unwrap();
+        assert_eq!(page.total_pages, 3);
+        assert_eq!(page.data.len(), 1);
+
+        let message = &page.data[0];
+        assert_eq!(message.id, 1234567890);
+        assert_eq!(message.sender.name, "Roblox");
+        assert_eq!(message.recipient.as_ref().unwrap().id, 156);
+        assert!(message.is_system_message);
+        assert!(!message.is_read);
+    }
+
+    #[test]
+    fn decodes_announcements() {
+        let body = br#"{
+            "collection": [{
+                "id": 42,
+                "sender": {"hasVerifiedBadge": true, "id": 1, "name": "Roblox", "displayName": "Roblox"},
+                "subject": "Announcement",
+                "body": "Something new",
+                "created": "2024-01-01T00:00:00.000Z",
+                "updated": "2024-01-01T00:00:00.000Z"
+            }],
+            "totalCollectionSize": 1
+        }"#;
+
+        let announcements: Announcements =
+            crate::Https::decode(reqwest::StatusCode::OK, body).unwrap();
+        assert_eq!(announcements.data.len(), 1);
+        assert!(announcements.data[0].recipient.is_none());
+    }
+}
@@ -0,0 +1,99 @@
+use crate::UserId;
+use serde::{Deserialize, Serialize};
+
+/// Tab of the private message box
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageTab {
+    #[default]
+    Inbox,
+    Sent,
+    Archive,
+    /// Announcements from Roblox
+    News,
+}
+
+impl MessageTab {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            MessageTab::Inbox => "Inbox",
+            MessageTab::Sent => "Sent",
+            MessageTab::Archive => "Archive",
+            MessageTab::News => "News",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MessageUser {
+    pub id: UserId,
+    pub name: String,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    #[serde(rename = "hasVerifiedBadge", default)]
+    pub has_verified_badge: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Message {
+    pub id: u64,
+    pub sender: MessageUser,
+    /// Missing on announcements
+    pub recipient: Option<MessageUser>,
+    pub subject: String,
+    pub body: String,
+    pub created: String,
+    pub updated: String,
+    #[serde(rename = "isRead", default)]
+    pub is_read: bool,
+    #[serde(rename = "isSystemMessage", default)]
+    pub is_system_message: bool,
+}
+
+/// Message Roblox failed to update in a bulk action
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FailedMessage {
+    #[serde(rename = "messageId")]
+    pub message_id: u64,
+    #[serde(rename = "errorMessage")]
+    pub error_message: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct MessagePage {
+    #[serde(rename = "collection")]
+    pub data: Vec<Message>,
+    #[serde(rename = "totalPages", default)]
+    pub total_pages: u64,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct Announcements {
+    #[serde(rename = "collection")]
+    pub data: Vec<Message>,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct BulkResponse {
+    #[serde(rename = "failedMessages", default)]
+    pub failed_messages: Vec<FailedMessage>,
+}
+
+#[derive(Serialize, Debug)]
+pub(crate) struct SendMessage<'a> {
+    #[serde(rename = "userId")]
+    pub user_id: UserId,
+    #[serde(rename = "recipientId")]
+    pub recipient_id: UserId,
+    pub subject: &'a str,
+    pub body: &'a str,
+    #[serde(rename = "replyMessageId")]
+    pub reply_message_id: Option<u64>,
+    #[serde(rename = "includePreviousMessage")]
+    pub include_previous_message: bool,
+}
+
+impl std::fmt::Display for Message {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Message(id={}, subject={})", self.id, self.subject)
+    }
+}
@@ -0,0 +1,104 @@
+use reqwest::{Method, StatusCode, Url};
+use std::time::Duration;
+
+/// Hooks called by `Https` for every request it sends.
+/// All methods have no-op defaults so implementors only need to override the events they care about.
+///
+/// # Example
+/// ```no_run
+/// use robloxapi::MetricsRecorder;
+/// use std::time::Duration;
+///
+/// struct Logger;
+///
+/// impl MetricsRecorder for Logger {
+///     fn record_request(&self, family: &str, method: &robloxapi::Method, status: Option<robloxapi::StatusCode>, latency: Duration) {
+///         println!("{} {} -> {:?} in {:?}", method, family, status, latency);
+///     }
+/// }
+///
+/// let mut client = robloxapi::Client::new();
+/// client.set_metrics_recorder(Logger);
+/// ```
+pub trait MetricsRecorder: Send + Sync {
+    /// Called once per attempt with the endpoint family (e.g. `games`), the response status
+    /// (`None` when the request failed before a response was received) and the attempt latency.
+    fn record_request(
+        &self,
+        _family: &str,
+        _method: &Method,
+        _status: Option<StatusCode>,
+        _latency: Duration,
+    ) {
+    }
+
+    /// Called when a request is about to be retried.
+    fn record_retry(&self, _family: &str) {}
+
+    /// Called whenever Roblox answers with `429 Too Many Requests`.
+    fn record_rate_limited(&self, _family: &str) {}
+}
+
+/// Recorder used when no other recorder has been set.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopRecorder;
+
+impl MetricsRecorder for NoopRecorder {}
+
+/// Recorder forwarding every event to the [`metrics`](https://docs.rs/metrics) facade.
+///
+/// Emits `robloxapi_requests_total`, `robloxapi_request_errors_total`,
+/// `robloxapi_request_duration_seconds`, `robloxapi_retries_total` and
+/// `robloxapi_rate_limited_total`, all labelled with the endpoint `family`.
+#[cfg(feature = "metrics")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MetricsCrateRecorder;
+
+#[cfg(feature = "metrics")]
+impl MetricsRecorder for MetricsCrateRecorder {
+    fn record_request(
+        &self,
+        family: &str,
+        method: &Method,
+        status: Option<StatusCode>,
+        latency: Duration,
+    ) {
+        let status_label = status
+            .map(|status| status.as_u16().to_string())
+            .unwrap_or_else(|| "error".to_owned());
+
+        metrics::counter!(
+            "robloxapi_requests_total",
+            "family" => family.to_owned(),
+            "method" => method.to_string(),
+            "status" => status_label
+        )
+        .increment(1);
+        metrics::histogram!(
+            "robloxapi_request_duration_seconds",
+            "family" => family.to_owned(),
+            "method" => method.to_string()
+        )
+        .record(latency.as_secs_f64());
+
+        if !status.is_some_and(|status| status.is_success()) {
+            metrics::counter!("robloxapi_request_errors_total", "family" => family.to_owned())
+                .increment(1);
+        }
+    }
+
+    fn record_retry(&self, family: &str) {
+        metrics::counter!("robloxapi_retries_total", "family" => family.to_owned()).increment(1);
+    }
+
+    fn record_rate_limited(&self, family: &str) {
+        metrics::counter!("robloxapi_rate_limited_total", "family" => family.to_owned())
+            .increment(1);
+    }
+}
+
+/// Get the endpoint family of a url, which is the Roblox subdomain (`https://games.roblox.com/v1` -> `games`)
+pub(crate) fn endpoint_family(url: &Url) -> String {
+    let host = url.host_str().unwrap_or("unknown");
+    host.strip_suffix(".roblox.com").unwrap_or(host).to_owned()
+}
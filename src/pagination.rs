@@ -0,0 +1,112 @@
+use crate::ApiResult;
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use reqwest::{Method, Url};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::future::Future;
+
+/// Stream of items from a cursor paginated endpoint, fetching the next page when the current one is exhausted.
+///
+/// # Example
+/// ```no_run
+/// use futures::TryStreamExt;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = robloxapi::Client::new();
+///     let mut results = client.search_users("builderman");
+///
+///     while let Some(user) = results.try_next().await.unwrap() {
+///         println!("{} ({})", user.name, user.id);
+///     }
+/// }
+/// ```
+pub type PageStream<T> = BoxStream<'static, ApiResult<T>>;
+
+/// Order in which paginated endpoints return their items
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "Asc",
+            SortOrder::Desc => "Desc",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct Page<T> {
+    #[serde(rename = "nextPageCursor")]
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<T>,
+}
+
+// Response of the endpoints counting friends, messages, etc.
+#[derive(Deserialize, Debug)]
+pub(crate) struct Count {
+    pub count: u64,
+}
+
+// Stream every item of a cursor paginated endpoint, starting from the first page
+pub(crate) fn paginate<T>(client: crate::Https, url: Url) -> PageStream<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    // `None` once the last page has been fetched
+    let pages = stream::try_unfold(Some(String::new()), move |cursor| {
+        let client = client.clone();
+        let mut url = url.clone();
+
+        async move {
+            let Some(cursor) = cursor else {
+                return Ok(None);
+            };
+            if !cursor.is_empty() {
+                url.query_pairs_mut().append_pair("cursor", &cursor);
+            }
+
+            let page = client
+                .request_json::<Page<T>, ()>(Method::GET, url.as_str(), None)
+                .await?;
+
+            let next_cursor = page.next_page_cursor.filter(|cursor| !cursor.is_empty());
+            ApiResult::Ok(Some((page.data, next_cursor)))
+        }
+    });
+
+    pages
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
+}
+
+// Stream every item of a page numbered endpoint, starting from `first_page`.
+// `fetch` returns the items of a page and whether more pages follow it.
+pub(crate) fn paginate_numbered<T, F, Fut>(first_page: u64, fetch: F) -> PageStream<T>
+where
+    T: Send + 'static,
+    F: FnMut(u64) -> Fut + Send + 'static,
+    Fut: Future<Output = ApiResult<(Vec<T>, bool)>> + Send,
+{
+    // `None` once the last page has been fetched
+    let pages = stream::try_unfold((Some(first_page), fetch), |(page, mut fetch)| async move {
+        let Some(page) = page else {
+            return Ok(None);
+        };
+
+        let (items, more) = fetch(page).await?;
+        let next = (more && !items.is_empty()).then_some(page + 1);
+        ApiResult::Ok(Some((items, (next, fetch))))
+    });
+
+    pages
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
+}
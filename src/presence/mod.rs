@@ -0,0 +1,281 @@
+pub mod models;
+
+use self::models::{PresenceChange, PresenceResponse, UserPresence};
+use crate::{ApiResult, Timer, User, UserId};
+
+use futures::future::{BoxFuture, FutureExt};
+use futures::stream::{self, BoxStream, StreamExt};
+use reqwest::Method;
+use serde_json::json;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+
+// Maximum number of users accepted by the presence endpoint per request
+const PRESENCE_BATCH_SIZE: usize = 50;
+
+impl crate::Client {
+    /// Get the presence of many users, split into as many requests as needed.
+    /// Locations of users who are not friends with the authenticated user may be hidden.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = robloxapi::Client::new();
+    ///     for presence in client.presence(&[1, 156, 242872495]).await.unwrap() {
+    ///         println!("{}", presence);
+    ///     }
+    /// }
+    /// ```
+    pub async fn presence(&self, user_ids: &[UserId]) -> ApiResult<Vec<UserPresence>> {
+        let mut presences = Vec::with_capacity(user_ids.len());
+
+        for batch in user_ids.chunks(PRESENCE_BATCH_SIZE) {
+            let response = self
+                .session
+                .request_json::<PresenceResponse, _>(
+                    Method::POST,
+                    crate::PRESENCE,
+                    Some(&json!({ "userIds": batch })),
+                )
+                .await?;
+            presences.extend(response.user_presences);
+        }
+
+        Ok(presences)
+    }
+
+    /// Poll the presence of the given users every `interval`, yielding only the changes.
+    /// The first poll records the initial presences without yielding anything, and a failed poll
+    /// yields its error before polling again after the next interval.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = robloxapi::Client::new();
+    ///     let mut changes = client.watch_presence(vec![1, 156], Duration::from_secs(30));
+    ///
+    ///     while let Some(change) = changes.next().await {
+    ///         if let Ok(change) = change {
+    ///             println!("{} is now {:?}", change.user, change.new.presence_type);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn watch_presence(
+        &self,
+        user_ids: Vec<UserId>,
+        interval: Duration,
+    ) -> BoxStream<'static, ApiResult<PresenceChange>> {
+        let client = self.clone();
+        let user_ids = Arc::new(user_ids);
+        let poll = move || {
+            let client = client.clone();
+            let user_ids = user_ids.clone();
+            async move { client.presence(&user_ids).await }.boxed()
+        };
+
+        watch(poll, self.session.timer.clone(), interval)
+    }
+}
+
+// Call `poll` every `interval`, yielding the differences between consecutive results
+fn watch<P>(
+    poll: P,
+    timer: Arc<dyn Timer>,
+    interval: Duration,
+) -> BoxStream<'static, ApiResult<PresenceChange>>
+where
+    P: FnMut() -> BoxFuture<'static, ApiResult<Vec<UserPresence>>> + Send + 'static,
+{
+    struct State<P> {
+        poll: P,
+        last: HashMap<UserId, UserPresence>,
+        pending: VecDeque<PresenceChange>,
+        polled: bool,
+    }
+
+    let state = State {
+        poll,
+        last: HashMap::new(),
+        pending: VecDeque::new(),
+        polled: false,
+    };
+
+    stream::unfold(state, move |mut state| {
+        let timer = timer.clone();
+
+        async move {
+            loop {
+                if let Some(change) = state.pending.pop_front() {
+                    return Some((Ok(change), state));
+                }
+
+                if state.polled {
+                    timer.sleep(interval).await;
+                }
+                state.polled = true;
+
+                let presences = match (state.poll)().await {
+                    Ok(presences) => presences,
+                    Err(error) => return Some((Err(error), state)),
+                };
+
+                for presence in presences {
+                    match state.last.get(&presence.user_id) {
+                        Some(old) if old.differs_from(&presence) => {
+                            state.pending.push_back(PresenceChange {
+                                user: presence.user_id,
+                                old: old.clone(),
+                                new: presence.clone(),
+                            });
+                        }
+                        _ => {}
+                    }
+                    state.last.insert(presence.user_id, presence);
+                }
+            }
+        }
+    })
+    .boxed()
+}
+
+impl User {
+    /// Get the presence of the user
+    pub async fn presence(&self) -> ApiResult<UserPresence> {
+        let mut response = self
+            .client
+            .as_ref()
+            .unwrap()
+            .request_json::<PresenceResponse, _>(
+                Method::POST,
+                crate::PRESENCE,
+                Some(&json!({ "userIds": [self.id.unwrap()] })),
+            )
+            .await?;
+
+        response
+            .user_presences
+            .pop()
+            .ok_or_else(|| crate::ApiError::RequestError {
+                reason: "No presence returned for user".to_owned(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ApiError;
+    use crate::PresenceType;
+    use std::future::Future;
+    use std::pin::Pin;
+
+    struct NoDelay;
+
+    impl Timer for NoDelay {
+        fn sleep(&self, _: Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+            Box::pin(std::future::ready(()))
+        }
+    }
+
+    fn presence(
+        user_id: UserId,
+        presence_type: PresenceType,
+        instance: Option<&str>,
+        last_online: &str,
+    ) -> UserPresence {
+        UserPresence {
+            user_id,
+            presence_type,
+            last_location: None,
+            place_id: instance.map(|_| 1818),
+            root_place_id: instance.map(|_| 1818),
+            universe_id: instance.map(|_| 13058),
+            game_instance_id: instance.map(str::to_owned),
+            last_online: Some(last_online.to_owned()),
+        }
+    }
+
+    // Watch a scripted sequence of poll results
+    fn watch_polls(
+        polls: Vec<ApiResult<Vec<UserPresence>>>,
+    ) -> BoxStream<'static, ApiResult<PresenceChange>> {
+        let mut polls = VecDeque::from(polls);
+        let poll = move || {
+            let result = polls.pop_front().expect("Polled more often than scripted");
+            async move { result }.boxed()
+        };
+
+        watch(poll, Arc::new(NoDelay), Duration::from_secs(30))
+    }
+
+    #[tokio::test]
+    async fn yields_only_changes() {
+        let changes: Vec<_> = watch_polls(vec![
+            // The first poll only records the initial state
+            Ok(vec![
+                presence(1, PresenceType::Online, None, "2024-01-01T00:00:00Z"),
+                presence(2, PresenceType::Offline, None, "2024-01-01T00:00:00Z"),
+            ]),
+            // Only `last_online` changed
+            Ok(vec![
+                presence(1, PresenceType::Online, None, "2024-01-01T00:01:00Z"),
+                presence(2, PresenceType::Offline, None, "2024-01-01T00:01:00Z"),
+            ]),
+            Ok(vec![
+                presence(1, PresenceType::InGame, Some("a"), "2024-01-01T00:02:00Z"),
+                presence(2, PresenceType::Offline, None, "2024-01-01T00:02:00Z"),
+            ]),
+            // Moved to another server of the same game
+            Ok(vec![
+                presence(1, PresenceType::InGame, Some("b"), "2024-01-01T00:03:00Z"),
+                presence(2, PresenceType::Offline, None, "2024-01-01T00:03:00Z"),
+            ]),
+        ])
+        .take(2)
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+        assert_eq!(changes[0].user, 1);
+        assert_eq!(changes[0].old.presence_type, PresenceType::Online);
+        assert_eq!(changes[0].new.game_instance_id.as_deref(), Some("a"));
+        assert_eq!(changes[1].user, 1);
+        assert_eq!(changes[1].old.game_instance_id.as_deref(), Some("a"));
+        assert_eq!(changes[1].new.game_instance_id.as_deref(), Some("b"));
+    }
+
+    #[tokio::test]
+    async fn keeps_state_across_errors() {
+        let mut changes = watch_polls(vec![
+            Ok(vec![presence(
+                1,
+                PresenceType::Online,
+                None,
+                "2024-01-01T00:00:00Z",
+            )]),
+            Err(ApiError::RateLimited),
+            Ok(vec![presence(
+                1,
+                PresenceType::Offline,
+                None,
+                "2024-01-01T00:02:00Z",
+            )]),
+        ]);
+
+        assert!(matches!(
+            changes.next().await,
+            Some(Err(ApiError::RateLimited))
+        ));
+
+        let change = changes.next().await.unwrap().unwrap();
+        assert_eq!(change.old.presence_type, PresenceType::Online);
+        assert_eq!(change.new.presence_type, PresenceType::Offline);
+    }
+}
@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+/// What a user is currently doing on Roblox
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(from = "u8", into = "u8")]
+pub enum PresenceType {
+    Offline,
+    Online,
+    InGame,
+    InStudio,
+    Invisible,
+    /// A presence type not known to this version of the crate
+    Other(u8),
+}
+
+impl From<u8> for PresenceType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => PresenceType::Offline,
+            1 => PresenceType::Online,
+            2 => PresenceType::InGame,
+            3 => PresenceType::InStudio,
+            4 => PresenceType::Invisible,
+            other => PresenceType::Other(other),
+        }
+    }
+}
+
+impl From<PresenceType> for u8 {
+    fn from(value: PresenceType) -> Self {
+        match value {
+            PresenceType::Offline => 0,
+            PresenceType::Online => 1,
+            PresenceType::InGame => 2,
+            PresenceType::InStudio => 3,
+            PresenceType::Invisible => 4,
+            PresenceType::Other(other) => other,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct UserPresence {
+    #[serde(rename = "userId")]
+    pub user_id: u64,
+    #[serde(rename = "userPresenceType")]
+    pub presence_type: PresenceType,
+    #[serde(rename = "lastLocation")]
+    pub last_location: Option<String>,
+    #[serde(rename = "placeId")]
+    pub place_id: Option<u64>,
+    #[serde(rename = "rootPlaceId")]
+    pub root_place_id: Option<u64>,
+    #[serde(rename = "universeId")]
+    pub universe_id: Option<u64>,
+    /// Id of the server instance the user is in
+    #[serde(rename = "gameId")]
+    pub game_instance_id: Option<String>,
+    #[serde(rename = "lastOnline")]
+    pub last_online: Option<String>,
+}
+
+impl UserPresence {
+    // Check if the user moved between states, ignoring fields like `last_online` which change on every poll
+    pub(crate) fn differs_from(&self, other: &UserPresence) -> bool {
+        self.presence_type != other.presence_type
+            || self.place_id != other.place_id
+            || self.universe_id != other.universe_id
+            || self.game_instance_id != other.game_instance_id
+    }
+}
+
+/// A change in the presence of a watched user, see `Client::watch_presence`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PresenceChange {
+    pub user: crate::UserId,
+    pub old: UserPresence,
+    pub new: UserPresence,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct PresenceResponse {
+    #[serde(rename = "userPresences")]
+    pub user_presences: Vec<UserPresence>,
+}
+
+impl std::fmt::Display for UserPresence {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "UserPresence(user_id={}, presence_type={:?}, last_location={})",
+            self.user_id,
+            self.presence_type,
+            self.last_location.as_deref().unwrap_or("unknown")
+        )
+    }
+}
@@ -0,0 +1,67 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+/// Timer used by `Https` to wait between retries, so the library does not depend on a specific async runtime.
+///
+/// With the `tokio` feature (enabled by default) `TokioTimer` is used, otherwise `ThreadTimer`.
+/// Note that the underlying `reqwest` client still needs a tokio reactor to send requests,
+/// async-std users can provide one with async-std's `tokio1` feature.
+///
+/// # Example
+/// ```ignore
+/// use std::{future::Future, pin::Pin, time::Duration};
+///
+/// struct AsyncStdTimer;
+///
+/// impl robloxapi::Timer for AsyncStdTimer {
+///     fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+///         Box::pin(async_std::task::sleep(duration))
+///     }
+/// }
+///
+/// let mut client = robloxapi::Client::new();
+/// client.set_timer(AsyncStdTimer);
+/// ```
+pub trait Timer: Send + Sync {
+    /// Return a future completing once `duration` has elapsed
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send>>;
+}
+
+/// Timer backed by `tokio::time::sleep`
+#[cfg(feature = "tokio")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TokioTimer;
+
+#[cfg(feature = "tokio")]
+impl Timer for TokioTimer {
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// Runtime independent timer which sleeps on a separate thread
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ThreadTimer;
+
+impl Timer for ThreadTimer {
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        let (sender, receiver) = futures::channel::oneshot::channel::<()>();
+        std::thread::spawn(move || {
+            std::thread::sleep(duration);
+            let _ = sender.send(());
+        });
+
+        Box::pin(async move {
+            let _ = receiver.await;
+        })
+    }
+}
+
+// Timer used when none has been set on the client
+pub(crate) fn default_timer() -> std::sync::Arc<dyn Timer> {
+    #[cfg(feature = "tokio")]
+    return std::sync::Arc::new(TokioTimer);
+    #[cfg(not(feature = "tokio"))]
+    return std::sync::Arc::new(ThreadTimer);
+}
@@ -0,0 +1,285 @@
+pub mod models;
+
+use self::models::{
+    AvatarThumbnailType, Thumbnail, ThumbnailImage, ThumbnailOptions, ThumbnailState,
+};
+use crate::pagination::Page;
+use crate::{ApiError, ApiResult, Timer, User, UserId};
+
+use reqwest::{header, Method, Url};
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::Instant;
+
+// Maximum number of targets accepted by the thumbnail endpoints per request
+const THUMBNAILS_BATCH_SIZE: usize = 100;
+
+async fn avatar_thumbnails(
+    client: &crate::Https,
+    kind: AvatarThumbnailType,
+    user_ids: &[UserId],
+    options: &ThumbnailOptions,
+) -> ApiResult<Vec<Thumbnail>> {
+    let fetch = |batch: Vec<UserId>| async move {
+        let ids = batch
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let url = Url::parse_with_params(
+            &format!("{}/{}", crate::THUMBNAILS, kind.path()),
+            &[
+                ("userIds", ids.as_str()),
+                ("size", options.size.as_str()),
+                ("format", options.format.as_str()),
+                (
+                    "isCircular",
+                    if options.is_circular { "true" } else { "false" },
+                ),
+            ],
+        )
+        .expect("Failed to build thumbnails url");
+
+        Ok(client
+            .request_json::<Page<Thumbnail>, ()>(Method::GET, url.as_str(), None)
+            .await?
+            .data)
+    };
+
+    poll_thumbnails(user_ids, fetch, client.timer.as_ref(), options).await
+}
+
+// Fetch the thumbnails in batches with `fetch`, fetching the pending ones again every `poll_interval`
+// until they are all done or `timeout` would be exceeded
+async fn poll_thumbnails<F, Fut>(
+    user_ids: &[UserId],
+    mut fetch: F,
+    timer: &dyn Timer,
+    options: &ThumbnailOptions,
+) -> ApiResult<Vec<Thumbnail>>
+where
+    F: FnMut(Vec<UserId>) -> Fut,
+    Fut: Future<Output = ApiResult<Vec<Thumbnail>>>,
+{
+    let deadline = Instant::now() + options.timeout;
+    let mut thumbnails: HashMap<u64, Thumbnail> = HashMap::with_capacity(user_ids.len());
+    let mut pending: Vec<UserId> = user_ids.to_vec();
+
+    loop {
+        for batch in pending.chunks(THUMBNAILS_BATCH_SIZE) {
+            for thumbnail in fetch(batch.to_vec()).await? {
+                thumbnails.insert(thumbnail.target_id, thumbnail);
+            }
+        }
+
+        pending.retain(|id| {
+            thumbnails
+                .get(id)
+                .is_none_or(|thumbnail| thumbnail.state == ThumbnailState::Pending)
+        });
+        if pending.is_empty() || Instant::now() + options.poll_interval > deadline {
+            break;
+        }
+        timer.sleep(options.poll_interval).await;
+    }
+
+    // Keep the order of the requested ids, thumbnails still pending after the timeout are returned as is
+    Ok(user_ids
+        .iter()
+        .filter_map(|id| thumbnails.remove(id))
+        .collect())
+}
+
+impl crate::Client {
+    /// Get avatar thumbnails of many users, waiting for thumbnails which are still being rendered.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use robloxapi::{AvatarThumbnailType, ThumbnailOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = robloxapi::Client::new();
+    ///     let thumbnails = client
+    ///         .avatar_thumbnails(AvatarThumbnailType::Headshot, &[1, 156], &ThumbnailOptions::default())
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn avatar_thumbnails(
+        &self,
+        kind: AvatarThumbnailType,
+        user_ids: &[UserId],
+        options: &ThumbnailOptions,
+    ) -> ApiResult<Vec<Thumbnail>> {
+        avatar_thumbnails(&self.session, kind, user_ids, options).await
+    }
+
+    /// Download the image of a completed thumbnail.
+    /// Images are served by the rbxcdn.com CDN, so they are requested without the cookie and x-csrf-token.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use robloxapi::{AvatarThumbnailType, ThumbnailOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     let user = client.user(156).await;
+    ///     let thumbnail = user
+    ///         .avatar_thumbnail(AvatarThumbnailType::Headshot, &ThumbnailOptions::default())
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     let image = client.download_thumbnail(&thumbnail).await.unwrap();
+    ///     std::fs::write("headshot.png", &image.bytes).unwrap();
+    /// }
+    /// ```
+    pub async fn download_thumbnail(&self, thumbnail: &Thumbnail) -> ApiResult<ThumbnailImage> {
+        let url = match (&thumbnail.state, &thumbnail.image_url) {
+            (ThumbnailState::Completed, Some(url)) => url,
+            (state, _) => {
+                return Err(ApiError::RequestError {
+                    reason: format!("Thumbnail is not available ({:?})", state),
+                })
+            }
+        };
+
+        let response = self.session.send(self.session.anonymous.get(url)).await?;
+        let status_code = response.status();
+        if !status_code.is_success() {
+            return Err(ApiError::Roblox {
+                status_code,
+                reason: status_code
+                    .canonical_reason()
+                    .unwrap_or("Unknown error")
+                    .to_owned(),
+            });
+        }
+
+        let content_type = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+
+        Ok(ThumbnailImage {
+            bytes: response.bytes().await?.to_vec(),
+            content_type,
+        })
+    }
+}
+
+impl User {
+    /// Get an avatar thumbnail of the user
+    pub async fn avatar_thumbnail(
+        &self,
+        kind: AvatarThumbnailType,
+        options: &ThumbnailOptions,
+    ) -> ApiResult<Thumbnail> {
+        avatar_thumbnails(
+            self.client.as_ref().unwrap(),
+            kind,
+            &[self.id.unwrap()],
+            options,
+        )
+        .await?
+        .pop()
+        .ok_or_else(|| crate::ApiError::RequestError {
+            reason: "No thumbnail returned for user".to_owned(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ThreadTimer;
+    use std::time::Duration;
+
+    fn thumbnail(target_id: u64, state: ThumbnailState) -> Thumbnail {
+        Thumbnail {
+            target_id,
+            state,
+            image_url: (state == ThumbnailState::Completed).then(|| {
+                format!(
+                    "https://tr.rbxcdn.com/{}/150/150/AvatarHeadshot/Png",
+                    target_id
+                )
+            }),
+            version: None,
+        }
+    }
+
+    fn options(poll_interval: Duration, timeout: Duration) -> ThumbnailOptions {
+        ThumbnailOptions {
+            poll_interval,
+            timeout,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn polls_pending_until_completed() {
+        let mut requests: Vec<Vec<UserId>> = Vec::new();
+        let fetch = |batch: Vec<UserId>| {
+            let first = requests.is_empty();
+            requests.push(batch.clone());
+            async move {
+                Ok(batch
+                    .into_iter()
+                    .map(|id| match id {
+                        2 if first => thumbnail(id, ThumbnailState::Pending),
+                        _ => thumbnail(id, ThumbnailState::Completed),
+                    })
+                    .collect())
+            }
+        };
+
+        let thumbnails = poll_thumbnails(
+            &[1, 2, 3],
+            fetch,
+            &ThreadTimer,
+            &options(Duration::from_millis(10), Duration::from_secs(5)),
+        )
+        .await
+        .unwrap();
+
+        // Only the pending thumbnail is requested again
+        assert_eq!(requests, vec![vec![1, 2, 3], vec![2]]);
+        assert_eq!(
+            thumbnails.iter().map(|t| t.target_id).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert!(thumbnails
+            .iter()
+            .all(|t| t.state == ThumbnailState::Completed && t.image_url.is_some()));
+    }
+
+    #[tokio::test]
+    async fn returns_pending_after_timeout() {
+        let mut polls = 0;
+        let fetch = |batch: Vec<UserId>| {
+            polls += 1;
+            async move {
+                Ok(batch
+                    .into_iter()
+                    .map(|id| thumbnail(id, ThumbnailState::Pending))
+                    .collect())
+            }
+        };
+
+        let thumbnails = poll_thumbnails(
+            &[1],
+            fetch,
+            &ThreadTimer,
+            &options(Duration::from_millis(10), Duration::from_millis(50)),
+        )
+        .await
+        .unwrap();
+
+        assert!(polls > 1);
+        assert_eq!(thumbnails.len(), 1);
+        assert_eq!(thumbnails[0].state, ThumbnailState::Pending);
+    }
+}
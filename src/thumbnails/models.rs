@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Kind of avatar thumbnail to render
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvatarThumbnailType {
+    Headshot,
+    Bust,
+    FullBody,
+}
+
+impl AvatarThumbnailType {
+    pub(crate) fn path(&self) -> &'static str {
+        match self {
+            AvatarThumbnailType::Headshot => "users/avatar-headshot",
+            AvatarThumbnailType::Bust => "users/avatar-bust",
+            AvatarThumbnailType::FullBody => "users/avatar",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThumbnailFormat {
+    Png,
+    Jpeg,
+    Webp,
+}
+
+impl ThumbnailFormat {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            ThumbnailFormat::Png => "Png",
+            ThumbnailFormat::Jpeg => "Jpeg",
+            ThumbnailFormat::Webp => "Webp",
+        }
+    }
+}
+
+/// Options for thumbnail requests
+///
+/// `size` must be one of the sizes supported by the thumbnail type, e.g. `48x48`, `150x150` or `420x420`.
+/// Thumbnails still `Pending` are requested again every `poll_interval` until `timeout` has elapsed.
+#[derive(Debug, Clone)]
+pub struct ThumbnailOptions {
+    pub size: String,
+    pub format: ThumbnailFormat,
+    pub is_circular: bool,
+    pub poll_interval: Duration,
+    pub timeout: Duration,
+}
+
+impl Default for ThumbnailOptions {
+    fn default() -> Self {
+        Self {
+            size: "150x150".to_owned(),
+            format: ThumbnailFormat::Png,
+            is_circular: false,
+            poll_interval: Duration::from_secs(1),
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThumbnailState {
+    Completed,
+    Pending,
+    InReview,
+    Blocked,
+    Error,
+    TemporarilyUnavailable,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Thumbnail {
+    #[serde(rename = "targetId")]
+    pub target_id: u64,
+    pub state: ThumbnailState,
+    #[serde(rename = "imageUrl")]
+    pub image_url: Option<String>,
+    pub version: Option<String>,
+}
+
+/// Image downloaded with `Client::download_thumbnail`
+#[derive(Debug, Clone)]
+pub struct ThumbnailImage {
+    pub bytes: Vec<u8>,
+    /// Value of the `content-type` header, e.g. `image/png`
+    pub content_type: Option<String>,
+}
+
+#[cfg(feature = "image")]
+impl ThumbnailImage {
+    /// Decode the image into a pixel buffer
+    pub fn decode(&self) -> Result<image::DynamicImage, image::ImageError> {
+        let format = self
+            .content_type
+            .as_deref()
+            .and_then(image::ImageFormat::from_mime_type);
+
+        match format {
+            Some(format) => image::load_from_memory_with_format(&self.bytes, format),
+            None => image::load_from_memory(&self.bytes),
+        }
+    }
+}
+
+impl std::fmt::Display for Thumbnail {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Thumbnail(target_id={}, state={:?}, image_url={})",
+            self.target_id,
+            self.state,
+            self.image_url.as_deref().unwrap_or("none")
+        )
+    }
+}
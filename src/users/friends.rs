@@ -0,0 +1,247 @@
+use super::models::{FriendRequest, User};
+use crate::pagination::{self, Count, PageStream};
+use crate::{ApiResult, PresenceType, UserPresence};
+
+use futures::{StreamExt, TryStreamExt};
+use reqwest::{Method, Url};
+use serde::de::IgnoredAny;
+use serde_json::json;
+use std::collections::HashMap;
+
+impl User {
+    // Stream the users of a friends.roblox.com list, attaching the client to each of them
+    fn user_stream(&self, list: &str) -> PageStream<User> {
+        let client = self.client.clone().unwrap();
+        let url = Url::parse(&format!(
+            "{}/users/{}/{}",
+            crate::FRIENDS,
+            self.id.unwrap(),
+            list
+        ))
+        .expect("Failed to build friends url");
+
+        pagination::paginate::<User>(client.clone(), url)
+            .map_ok(move |mut user| {
+                user.client = Some(client.clone());
+                user
+            })
+            .boxed()
+    }
+
+    async fn count(&self, list: &str) -> ApiResult<u64> {
+        Ok(self
+            .client
+            .as_ref()
+            .unwrap()
+            .request_json::<Count, ()>(
+                Method::GET,
+                &format!(
+                    "{}/users/{}/{}/count",
+                    crate::FRIENDS,
+                    self.id.unwrap(),
+                    list
+                ),
+                None,
+            )
+            .await?
+            .count)
+    }
+
+    /// Get all friends of user, including their online status
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     let user = client.user(242872495).await;
+    ///     let friends: Vec<_> = user.friends().try_collect().await.unwrap();
+    /// }
+    /// ```
+    pub fn friends(&self) -> PageStream<User> {
+        self.user_stream("friends")
+    }
+
+    /// Get all friends of user joined with their presence, fetched with [`crate::Client::presence`].
+    /// `isonline` of every friend is updated from its presence, friends without a returned presence get `None`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     let user = client.user(242872495).await;
+    ///
+    ///     for (friend, presence) in user.friends_with_presence().await.unwrap() {
+    ///         if let Some(presence) = presence {
+    ///             println!("{:?} is {:?}", friend.username, presence.presence_type);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn friends_with_presence(&self) -> ApiResult<Vec<(User, Option<UserPresence>)>> {
+        let friends: Vec<User> = self.friends().try_collect().await?;
+        let user_ids: Vec<u64> = friends.iter().filter_map(|friend| friend.id).collect();
+
+        let client = crate::Client {
+            session: self.client.clone().unwrap(),
+        };
+        let mut presences: HashMap<u64, UserPresence> = client
+            .presence(&user_ids)
+            .await?
+            .into_iter()
+            .map(|presence| (presence.user_id, presence))
+            .collect();
+
+        Ok(friends
+            .into_iter()
+            .map(|mut friend| {
+                let presence = friend.id.and_then(|id| presences.remove(&id));
+                if let Some(presence) = &presence {
+                    friend.isonline = Some(presence.presence_type != PresenceType::Offline);
+                }
+                (friend, presence)
+            })
+            .collect())
+    }
+
+    /// Get the users following this user
+    pub fn followers(&self) -> PageStream<User> {
+        self.user_stream("followers?limit=100")
+    }
+
+    /// Get the users this user follows
+    pub fn followings(&self) -> PageStream<User> {
+        self.user_stream("followings?limit=100")
+    }
+
+    /// Get the number of friends of the user
+    pub async fn friend_count(&self) -> ApiResult<u64> {
+        self.count("friends").await
+    }
+
+    /// Get the number of users following this user
+    pub async fn follower_count(&self) -> ApiResult<u64> {
+        self.count("followers").await
+    }
+
+    /// Get the number of users this user follows
+    pub async fn following_count(&self) -> ApiResult<u64> {
+        self.count("followings").await
+    }
+
+    /// Check if this user follows `other`.
+    /// Searches whichever is shorter of this user's followings and the other user's followers.
+    ///
+    /// In the worst case the whole list is paged through at 100 users per request,
+    /// which takes many requests when both users have a large number of followers and followings.
+    pub async fn is_following(&self, other: &User) -> ApiResult<bool> {
+        let (mut users, target) = if self.following_count().await? <= other.follower_count().await?
+        {
+            (self.followings(), other.id)
+        } else {
+            (other.followers(), self.id)
+        };
+
+        while let Some(user) = users.try_next().await? {
+            if user.id == target {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+impl crate::Client {
+    // Send a body-less POST to friends.roblox.com, discarding the response
+    async fn friends_action(&self, path: &str) -> ApiResult<()> {
+        self.session
+            .request_json::<IgnoredAny, ()>(
+                Method::POST,
+                &format!("{}{}", crate::FRIENDS, path),
+                None,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Get the pending friend requests of the authenticated user, requires cookie
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     client.set_cookie("").await;
+    ///
+    ///     let mut requests = client.friend_requests();
+    ///     while let Some(request) = requests.try_next().await.unwrap() {
+    ///         client.accept_friend_request(request.user.id.unwrap()).await.unwrap();
+    ///     }
+    /// }
+    /// ```
+    pub fn friend_requests(&self) -> PageStream<FriendRequest> {
+        let client = self.session.clone();
+        let url = Url::parse(&format!("{}/my/friends/requests?limit=100", crate::FRIENDS))
+            .expect("Failed to build friend requests url");
+
+        pagination::paginate::<FriendRequest>(client.clone(), url)
+            .map_ok(move |mut request| {
+                request.user.client = Some(client.clone());
+                request
+            })
+            .boxed()
+    }
+
+    /// Accept the friend request sent by the given user, requires cookie
+    pub async fn accept_friend_request(&self, user_id: u64) -> ApiResult<()> {
+        self.friends_action(&format!("/users/{}/accept-friend-request", user_id))
+            .await
+    }
+
+    /// Decline the friend request sent by the given user, requires cookie
+    pub async fn decline_friend_request(&self, user_id: u64) -> ApiResult<()> {
+        self.friends_action(&format!("/users/{}/decline-friend-request", user_id))
+            .await
+    }
+
+    /// Decline every pending friend request, requires cookie
+    pub async fn decline_all_friend_requests(&self) -> ApiResult<()> {
+        self.friends_action("/user/friend-requests/decline-all")
+            .await
+    }
+
+    /// Send a friend request to the given user, requires cookie
+    pub async fn send_friend_request(&self, user_id: u64) -> ApiResult<()> {
+        self.session
+            .request_json::<IgnoredAny, _>(
+                Method::POST,
+                &format!("{}/users/{}/request-friendship", crate::FRIENDS, user_id),
+                Some(&json!({ "friendshipOriginSourceType": "UserProfile" })),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Remove the given user from the friends of the authenticated user, requires cookie
+    pub async fn unfriend(&self, user_id: u64) -> ApiResult<()> {
+        self.friends_action(&format!("/users/{}/unfriend", user_id))
+            .await
+    }
+
+    /// Follow the given user, requires cookie
+    pub async fn follow(&self, user_id: u64) -> ApiResult<()> {
+        self.friends_action(&format!("/users/{}/follow", user_id))
+            .await
+    }
+
+    /// Unfollow the given user, requires cookie
+    pub async fn unfollow(&self, user_id: u64) -> ApiResult<()> {
+        self.friends_action(&format!("/users/{}/unfollow", user_id))
+            .await
+    }
+}
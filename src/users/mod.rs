@@ -1,12 +1,21 @@
+mod friends;
 pub mod models;
 
-use self::models::User;
+use self::models::{SearchUser, User, UsernameHistoryEntry, UsernameLookup, UsernameResult};
+use crate::pagination::{self, Page, PageStream};
+use crate::{ApiError, ApiResult};
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::{StreamExt, TryStreamExt};
 use reqwest::Method;
+use reqwest::{StatusCode, Url};
+use serde_json::json;
 use std::collections::HashMap;
+use std::time::Duration;
 
 #[async_trait]
+#[allow(clippy::new_ret_no_self, clippy::wrong_self_convention)]
 pub trait UserBuilder {
     async fn new(self, client: &mut crate::Https) -> User;
 }
@@ -14,31 +23,18 @@ pub trait UserBuilder {
 #[async_trait]
 impl UserBuilder for &str {
     /// Create a new user by name
+    ///
+    /// # Panics
+    /// Panics if the request fails or no user has the given name,
+    /// use [`crate::Client::users_by_names`] to look names up without panicking.
     async fn new(self, client: &mut crate::Https) -> User {
-        let mut map = HashMap::new();
-        map.insert("usernames", vec![self]);
-
-        let data = client
-            .post(&format!("{}/usernames/users", crate::USER))
-            .await
-            .json(&map)
-            .header("content-length", serde_json::to_vec(&map).unwrap().len())
-            .send()
-            .await
-            .expect("Failed to get user info")
-            .json::<serde_json::Value>()
+        let mut lookup = users_by_names(client, &[self], false)
             .await
-            .expect("Failed to get user json");
-
-        if let Some(users) = data.get("data") {
-            if let Some(id) = users[0].get("id") {
-                let builder = id.as_i64().unwrap() as u64;
-                builder.new(client).await
-            } else {
-                panic!("Failed to find users with given name")
-            }
-        } else {
-            panic!("Request failed")
+            .expect("Failed to get user info");
+
+        match lookup.users.remove(self) {
+            Some(user) => user,
+            None => panic!("Failed to find users with given name"),
         }
     }
 }
@@ -47,125 +43,248 @@ impl UserBuilder for &str {
 impl UserBuilder for u64 {
     /// Create a new user with userid
     async fn new(self, client: &mut crate::Https) -> User {
-        let user: User = client
-            .client
-            .request(Method::GET, &format!("{}/users/{}", crate::BASE, self))
-            .send()
-            .await
-            .expect("Failed to get user info from base")
-            .json()
+        let mut user: User = client
+            .request(Method::GET, &format!("{}/users/{}", crate::USER, self))
             .await
-            .expect("Failed to update struct with base");
-
-        let mut u2 = User {
-            id: Some(self),
-            username: user.username,
-            avatarfinal: user.avatarfinal,
-            avataruri: user.avataruri,
-            isonline: user.isonline,
-            ..client
-                .client
-                .request(Method::GET, &format!("{}/users/{}", crate::USER, self))
-                .send()
-                .await
-                .expect("Failed to get user info from user")
-                .json()
-                .await
-                .expect("Failed to update struct with user")
-        };
-
-        u2.client = Some(client.clone());
-        u2
+            .expect("Failed to get user info");
+
+        user.client = Some(client.clone());
+        user
     }
 }
 
-impl User {
-    /// Get all friends of user, requires cookie
-    pub async fn friends(&mut self) -> Vec<User> {
-        if let Some(friends) = self.friends.clone() {
-            friends
-        } else {
-            let mut friends: Vec<User> = vec![];
-            let mut page: i32 = 1;
-            let mut page_string = format!("?page={}", page);
-
-            let mut data = self
-                .client
-                .as_mut()
-                .unwrap()
-                .client
-                .request(
-                    Method::GET,
-                    &format!(
-                        "{}/users/{}/friends{}",
-                        crate::BASE,
-                        self.id.unwrap(),
-                        page_string
-                    ),
-                )
-                .send()
-                .await
-                .expect("Failed to get friends list")
-                .json::<Vec<User>>()
-                .await
-                .expect("Failed to get friends json");
-
-            loop {
-                friends.extend_from_slice(&data[..]);
-
-                page += 1;
-                page_string = format!("?page={}", page);
-                data = self
-                    .client
-                    .as_mut()
-                    .unwrap()
-                    .client
-                    .request(
-                        Method::GET,
-                        &format!(
-                            "{}/users/{}/friends{}",
-                            crate::BASE,
-                            self.id.unwrap(),
-                            page_string
-                        ),
-                    )
-                    .send()
-                    .await
-                    .expect("Failed to get friends list")
-                    .json::<Vec<User>>()
-                    .await
-                    .expect("Failed to get friends json");
-
-                if data.is_empty() {
-                    break;
-                }
-            }
+// Number of results fetched per search page
+const SEARCH_PAGE_SIZE: usize = 100;
 
-            self.friends = Some(friends.clone());
-            friends
+// Maximum number of usernames accepted by `/usernames/users` per request
+const USERNAMES_BATCH_SIZE: usize = 100;
+
+async fn users_by_names(
+    client: &crate::Https,
+    usernames: &[&str],
+    exclude_banned: bool,
+) -> ApiResult<UsernameLookup> {
+    let mut lookup = UsernameLookup::default();
+
+    for batch in usernames.chunks(USERNAMES_BATCH_SIZE) {
+        let results = client
+            .request_json::<Page<UsernameResult>, _>(
+                Method::POST,
+                &format!("{}/usernames/users", crate::USER),
+                Some(&json!({ "usernames": batch, "excludeBannedUsers": exclude_banned })),
+            )
+            .await?
+            .data;
+
+        for mut result in results {
+            result.user.client = Some(client.clone());
+            lookup.users.insert(result.requested_username, result.user);
         }
     }
 
-    /// Check if user has asset, may require cookie
-    pub async fn has_asset(&mut self, asset_id: u64) -> bool {
+    lookup.missing = missing_names(usernames, &lookup.users);
+    Ok(lookup)
+}
+
+// Roblox echoes every resolved name back as it was requested, so unresolved names match exactly
+fn missing_names(usernames: &[&str], users: &HashMap<String, User>) -> Vec<String> {
+    usernames
+        .iter()
+        .filter(|name| !users.contains_key(**name))
+        .map(|name| name.to_string())
+        .collect()
+}
+
+impl crate::Client {
+    /// Resolve many usernames at once, reporting the names which did not resolve instead of failing.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = robloxapi::Client::new();
+    ///     let lookup = client
+    ///         .users_by_names(&["builderman", "not a real user"], false)
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     for name in lookup.missing {
+    ///         println!("Could not find {}", name);
+    ///     }
+    /// }
+    /// ```
+    pub async fn users_by_names(
+        &self,
+        usernames: &[&str],
+        exclude_banned: bool,
+    ) -> ApiResult<UsernameLookup> {
+        users_by_names(&self.session, usernames, exclude_banned).await
+    }
+
+    /// Search users by keyword. The search endpoint is heavily rate limited,
+    /// the stream yields `ApiError::RateLimited` once retrying did not help.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = robloxapi::Client::new();
+    ///     let users: Vec<_> = client.search_users("builder").try_collect().await.unwrap();
+    /// }
+    /// ```
+    pub fn search_users(&self, keyword: &str) -> PageStream<SearchUser> {
+        let url = Url::parse_with_params(
+            &format!("{}/users/search", crate::USER),
+            &[
+                ("keyword", keyword),
+                ("limit", &SEARCH_PAGE_SIZE.to_string()),
+            ],
+        )
+        .expect("Failed to build search url");
+
+        pagination::paginate(self.session.clone(), url)
+    }
+
+    /// Find the account which previously used the given username, if any.
+    /// Only the most relevant search results are checked.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     if let Some(user) = client.user_by_former_name("oldname").await.unwrap() {
+    ///         println!("oldname is now {}", user);
+    ///     }
+    /// }
+    /// ```
+    pub async fn user_by_former_name(&mut self, username: &str) -> ApiResult<Option<User>> {
+        let mut results = self.search_users(username).take(SEARCH_PAGE_SIZE);
+
+        while let Some(result) = results.try_next().await? {
+            if result
+                .previous_usernames
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(username))
+            {
+                return Ok(Some(self.user(result.id).await));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl User {
+    /// Get the previous usernames of the user, most recent first
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     let user = client.user(242872495).await;
+    ///     let names: Vec<String> = user.username_history().try_collect().await.unwrap();
+    /// }
+    /// ```
+    pub fn username_history(&self) -> PageStream<String> {
+        let url = Url::parse(&format!(
+            "{}/users/{}/username-history?limit=100&sortOrder=Desc",
+            crate::USER,
+            self.id.unwrap()
+        ))
+        .expect("Failed to build username history url");
+
+        pagination::paginate::<UsernameHistoryEntry>(self.client.clone().unwrap(), url)
+            .map_ok(|entry| entry.name)
+            .boxed()
+    }
+
+    /// Check if the user has Roblox Premium. Requires cookie, fails with
+    /// `ApiError::AuthorizationFailed` when the client is not authenticated.
+    ///
+    /// # Example
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = robloxapi::Client::new();
+    ///     client.set_cookie("").await;
+    ///     let user = client.user(156).await;
+    ///     let premium = user.has_premium().await.unwrap();
+    /// }
+    /// ```
+    pub async fn has_premium(&self) -> ApiResult<bool> {
         self.client
-            .as_mut()
+            .as_ref()
             .unwrap()
-            .client
-            .request(
+            .request_json::<bool, ()>(
                 Method::GET,
                 &format!(
-                    "{}/ownership/hasasset?userId={}&assetId={}",
-                    crate::BASE,
-                    self.id.unwrap(),
-                    asset_id
+                    "{}/users/{}/validate-membership",
+                    crate::PREMIUM,
+                    self.id.unwrap()
                 ),
+                None,
             )
-            .send()
-            .await
-            .expect("Failed to get ownership info")
-            .json::<bool>()
             .await
-            .expect("Failed to get ownership json")
+            .map_err(|error| match error {
+                ApiError::Roblox {
+                    status_code: StatusCode::UNAUTHORIZED,
+                    ..
+                } => ApiError::AuthorizationFailed,
+                error => error,
+            })
+    }
+
+    /// Get when the account was created, `None` if `created` is missing or invalid
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(self.created.as_deref()?)
+            .ok()
+            .map(|created| created.with_timezone(&Utc))
+    }
+
+    /// Get the age of the account, `None` if `created` is missing or invalid
+    ///
+    /// # Example
+    /// ```
+    /// let user: robloxapi::User =
+    ///     serde_json::from_str(r#"{"id": 1, "created": "2006-02-27T21:06:40.3Z"}"#).unwrap();
+    /// let years = user.account_age().unwrap().as_secs() / (365 * 24 * 60 * 60);
+    /// assert!(years >= 20);
+    /// ```
+    pub fn account_age(&self) -> Option<Duration> {
+        (Utc::now() - self.created_at()?).to_std().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: u64) -> User {
+        serde_json::from_value(json!({ "id": id })).unwrap()
+    }
+
+    #[test]
+    fn missing_names_are_the_unresolved_requests() {
+        let users = HashMap::from([
+            ("builderman".to_owned(), user(156)),
+            ("ROBLOX".to_owned(), user(1)),
+        ]);
+
+        assert_eq!(
+            missing_names(&["builderman", "not a real user", "ROBLOX"], &users),
+            vec!["not a real user".to_owned()]
+        );
+        // Names are matched exactly as they were requested
+        assert_eq!(
+            missing_names(&["Builderman"], &users),
+            vec!["Builderman".to_owned()]
+        );
+        assert!(missing_names(&[], &users).is_empty());
     }
 }
@@ -1,26 +1,84 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct User {
     #[serde(skip)]
     pub client: Option<crate::Https>,
-    #[serde(skip)]
-    pub friends: Option<Vec<User>>,
 
-    #[serde(rename = "Id")]
     pub id: Option<u64>,
-    #[serde(rename = "Username")]
+    #[serde(rename = "name")]
     pub username: Option<String>,
-    #[serde(rename = "AvatarFinal")]
-    pub avatarfinal: Option<bool>,
-    #[serde(rename = "AvatarUri")]
-    pub avataruri: Option<String>,
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
     pub created: Option<String>,
     pub description: Option<String>,
     #[serde(rename = "isBanned")]
     pub isbanned: Option<bool>,
-    #[serde(rename = "IsOnline")]
+    // Only returned when listing friends
+    #[serde(rename = "isOnline")]
     pub isonline: Option<bool>,
+    #[serde(rename = "hasVerifiedBadge")]
+    pub has_verified_badge: Option<bool>,
+    #[serde(rename = "externalAppDisplayName")]
+    pub external_app_display_name: Option<String>,
+}
+
+/// Result of resolving a batch of usernames, see `Client::users_by_names`
+#[derive(Debug, Clone, Default)]
+pub struct UsernameLookup {
+    /// Resolved users keyed by the username as it was requested
+    pub users: HashMap<String, User>,
+    /// Requested usernames which did not resolve to a user
+    pub missing: Vec<String>,
+}
+
+/// A user returned by `Client::search_users`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SearchUser {
+    pub id: u64,
+    pub name: String,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    #[serde(rename = "previousUsernames", default)]
+    pub previous_usernames: Vec<String>,
+    #[serde(rename = "hasVerifiedBadge")]
+    pub has_verified_badge: bool,
+}
+
+/// A pending friend request, see `Client::friend_requests`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FriendRequest {
+    /// The user who sent the request
+    #[serde(flatten)]
+    pub user: User,
+    #[serde(rename = "friendRequest")]
+    pub details: FriendRequestDetails,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FriendRequestDetails {
+    #[serde(rename = "sentAt")]
+    pub sent_at: String,
+    #[serde(rename = "senderId")]
+    pub sender_id: u64,
+    #[serde(rename = "sourceUniverseId")]
+    pub source_universe_id: Option<u64>,
+    #[serde(rename = "originSourceType")]
+    pub origin_source_type: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct UsernameHistoryEntry {
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct UsernameResult {
+    #[serde(rename = "requestedUsername")]
+    pub requested_username: String,
+    #[serde(flatten)]
+    pub user: User,
 }
 
 impl std::fmt::Display for User {
@@ -28,11 +86,11 @@ impl std::fmt::Display for User {
         let unknown = String::from("unknown");
         write!(
             f,
-            "User(id={}, username={}, created={}, isonline={}, isbanned={})",
+            "User(id={}, username={}, display_name={}, created={}, isbanned={})",
             self.id.as_ref().unwrap(),
             self.username.as_ref().unwrap_or(&unknown),
+            self.display_name.as_ref().unwrap_or(&unknown),
             self.created.as_ref().unwrap_or(&unknown),
-            self.isonline.as_ref().unwrap_or(&false),
             self.isbanned.as_ref().unwrap_or(&false)
         )
     }
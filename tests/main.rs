@@ -1,6 +1,3 @@
-use robloxapi;
-use tokio;
-
 const COOKIE: &str = "";
 #[tokio::main]
 async fn main() {
@@ -15,7 +12,7 @@ async fn main() {
 
     let mut game = client.game(3272915504).await.expect("Error");
     // dbg!(&game);
-    println!("{:?}", game.servers().await);
+    println!("{:?}", game.servers().await.expect("Error"));
 
     let dev_product = game.create_dev_product("17", 17).await;
 